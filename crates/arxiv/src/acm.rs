@@ -0,0 +1,847 @@
+use crate::{CategoryId, CsSubject, Subject};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// An error that can occur when parsing ACM classes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcmError<'a> {
+	/// The class is neither formatted like a 1998 code nor like a CCS 2012 concept path
+	InvalidClass(&'a str),
+	/// The CCS 2012 concept path does not start with a top-level concept
+	UnknownConcept(&'a str),
+}
+
+impl Error for AcmError<'_> {}
+
+impl Display for AcmError<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::InvalidClass(s) => write!(f, "Invalid ACM class: {s}"),
+			Self::UnknownConcept(s) => {
+				write!(f, "The ACM CCS 2012 concept \"{s}\" has no top-level concept")
+			}
+		}
+	}
+}
+
+/// A level below the section of a 1998 ACM code, such as `2` in `I.2`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AcmLevel {
+	Num(u8),
+	/// The miscellaneous class of its parent, written as `m` (e.g. `I.2.m`)
+	Misc,
+}
+
+impl Display for AcmLevel {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Num(n) => write!(f, "{n}"),
+			Self::Misc => f.write_str("m"),
+		}
+	}
+}
+
+/// A code of the [1998 ACM Computing Classification System][acm-1998], such as `I.2.6`
+///
+/// # Examples
+/// ```
+/// use arxiv::{Acm1998Class, AcmLevel, CategoryId};
+///
+/// let class = Acm1998Class::try_from("I.2.7").unwrap();
+/// assert_eq!(class.section(), 'I');
+/// assert_eq!(class.subsection(), Some(AcmLevel::Num(2)));
+/// assert_eq!(class.topic(), Some(AcmLevel::Num(7)));
+/// assert_eq!(class.name(), "Computing Methodologies");
+/// assert_eq!(class.categories(), [CategoryId::try_from("cs.CL").unwrap()]);
+/// ```
+///
+/// [acm-1998]: <https://www.acm.org/publications/computing-classification-system/1998>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Acm1998Class {
+	section: u8,
+	subsection: Option<AcmLevel>,
+	topic: Option<AcmLevel>,
+}
+
+impl Acm1998Class {
+	const fn new(section: u8, subsection: Option<AcmLevel>, topic: Option<AcmLevel>) -> Self {
+		Self {
+			section,
+			subsection,
+			topic,
+		}
+	}
+
+	/// The letter of the top-level section (e.g. `I` in `I.2.6`)
+	#[must_use]
+	#[inline]
+	pub const fn section(&self) -> char {
+		self.section as char
+	}
+
+	/// The second level of the code (e.g. `2` in `I.2.6`)
+	#[must_use]
+	#[inline]
+	pub const fn subsection(&self) -> Option<AcmLevel> {
+		self.subsection
+	}
+
+	/// The third level of the code (e.g. `6` in `I.2.6`)
+	#[must_use]
+	#[inline]
+	pub const fn topic(&self) -> Option<AcmLevel> {
+		self.topic
+	}
+
+	/// The name of the top-level section that the code falls under.
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		SECTIONS[(self.section - b'A') as usize]
+	}
+
+	/// Checks if the other code is equal to or falls under this one.
+	#[must_use]
+	pub fn contains(&self, other: &Self) -> bool {
+		self.section == other.section
+			&& self.subsection.is_none_or(|l| other.subsection == Some(l))
+			&& self.topic.is_none_or(|l| other.topic == Some(l))
+	}
+
+	/// The `cs` categories that most closely correspond to the code, based on the ACM classes
+	/// listed in arXiv's category descriptions.
+	///
+	/// When the code falls under several mapped classes, only the most specific ones are kept;
+	/// for example, `H.5.1` maps to `cs.MM` rather than `cs.HC`. Some codes are listed by more
+	/// than one category (such as `I.2.6` for both `cs.LG` and `cs.NE`), in which case all of them
+	/// are returned.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Acm1998Class, CategoryId};
+	///
+	/// let class = Acm1998Class::try_from("H.5.1").unwrap();
+	/// assert_eq!(class.categories(), [CategoryId::try_from("cs.MM").unwrap()]);
+	/// ```
+	#[must_use]
+	pub fn categories(&self) -> Vec<CategoryId> {
+		let depth = |class: &Self| {
+			usize::from(class.subsection.is_some()) + usize::from(class.topic.is_some())
+		};
+		most_specific(CsSubject::ALL.iter().flat_map(|subject| {
+			subject
+				.acm_classes()
+				.iter()
+				.filter(|class| class.contains(self))
+				.map(move |class| (depth(class), *subject))
+		}))
+	}
+
+	fn parse(s: &str) -> Option<Self> {
+		let mut parts = s.split('.');
+		let section = match parts.next()?.as_bytes() {
+			[c @ b'A'..=b'K'] => *c,
+			_ => return None,
+		};
+		let subsection = match parts.next() {
+			Some(p) => Some(parse_level(p, 1)?),
+			None => None,
+		};
+		let topic = match parts.next() {
+			Some(p) => Some(parse_level(p, 2)?),
+			None => None,
+		};
+		match parts.next() {
+			None => Some(Self::new(section, subsection, topic)),
+			Some(_) => None,
+		}
+	}
+}
+
+fn parse_level(s: &str, max_digits: usize) -> Option<AcmLevel> {
+	match s {
+		"m" => Some(AcmLevel::Misc),
+		_ if (1..=max_digits).contains(&s.len()) && s.bytes().all(|c| c.is_ascii_digit()) => {
+			s.parse().ok().map(AcmLevel::Num)
+		}
+		_ => None,
+	}
+}
+
+impl<'a> TryFrom<&'a str> for Acm1998Class {
+	type Error = AcmError<'a>;
+
+	fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+		Self::parse(s).ok_or(AcmError::InvalidClass(s))
+	}
+}
+
+impl Display for Acm1998Class {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(f, "{}", self.section())?;
+		if let Some(subsection) = self.subsection {
+			write!(f, ".{subsection}")?;
+		}
+		if let Some(topic) = self.topic {
+			write!(f, ".{topic}")?;
+		}
+		Ok(())
+	}
+}
+
+/// A concept of the [2012 ACM Computing Classification System][ccs-2012], written as a path
+/// from a top-level concept (e.g. `Computing methodologies~Machine learning`)
+///
+/// Concepts are separated by `~` or `→` in the input, and always displayed with `~`. The
+/// top-level concept is validated and normalized to its canonical spelling, while the deeper
+/// concepts are kept as written.
+///
+/// # Examples
+/// ```
+/// use arxiv::{CategoryId, CcsConcept};
+///
+/// let concept = CcsConcept::try_from("computing methodologies → Machine learning").unwrap();
+/// assert_eq!(concept.to_string(), "Computing methodologies~Machine learning");
+/// assert_eq!(concept.categories(), [CategoryId::try_from("cs.LG").unwrap()]);
+/// ```
+///
+/// [ccs-2012]: <https://dl.acm.org/ccs>
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CcsConcept {
+	path: Vec<String>,
+}
+
+impl CcsConcept {
+	/// The concepts from the top-level concept downwards
+	#[must_use]
+	pub fn path(&self) -> &[String] {
+		&self.path
+	}
+
+	/// Checks if the concept is, or falls under, the given concept path (ignoring ASCII case).
+	#[must_use]
+	pub fn starts_with(&self, path: &[&str]) -> bool {
+		path.len() <= self.path.len()
+			&& path
+				.iter()
+				.zip(&self.path)
+				.all(|(a, b)| a.eq_ignore_ascii_case(b))
+	}
+
+	/// The `cs` categories that most closely correspond to the concept.
+	///
+	/// When the concept falls under several mapped concepts, only the deepest ones are kept; for
+	/// example, `Computing methodologies~Artificial intelligence~Computer vision` maps to `cs.CV`
+	/// rather than `cs.AI`.
+	#[must_use]
+	pub fn categories(&self) -> Vec<CategoryId> {
+		most_specific(CsSubject::ALL.iter().flat_map(|subject| {
+			subject
+				.ccs_concepts()
+				.iter()
+				.filter(|path| self.starts_with(path))
+				.map(move |path| (path.len(), *subject))
+		}))
+	}
+
+	fn parse(s: &str) -> Option<Self> {
+		let path: Vec<String> = s
+			.split(['~', '→'])
+			.map(|concept| concept.trim().to_owned())
+			.collect();
+		if path.iter().any(String::is_empty) {
+			return None;
+		}
+		Some(Self { path })
+	}
+}
+
+impl<'a> TryFrom<&'a str> for CcsConcept {
+	type Error = AcmError<'a>;
+
+	fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+		let mut concept = Self::parse(s).ok_or(AcmError::InvalidClass(s))?;
+		let top = CONCEPTS
+			.iter()
+			.find(|top| top.eq_ignore_ascii_case(&concept.path[0]))
+			.ok_or(AcmError::UnknownConcept(s))?;
+		concept.path[0] = (*top).to_owned();
+		Ok(concept)
+	}
+}
+
+impl Display for CcsConcept {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.path.join("~"))
+	}
+}
+
+/// A class of the ACM Computing Classification System, as listed in the `acm-class` field of an
+/// article's arXiv metadata
+///
+/// # Examples
+/// ```
+/// use arxiv::AcmClass;
+///
+/// let classes = AcmClass::parse_list("I.2.6; I.5.1, F.2.2").unwrap();
+/// assert_eq!(classes.len(), 3);
+///
+/// let classes = AcmClass::parse_list("I.2.6; Theory of computation~Logic").unwrap();
+/// assert!(matches!(classes[1], AcmClass::Ccs2012(_)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AcmClass {
+	/// A code of the 1998 ACM Computing Classification System
+	Acm1998(Acm1998Class),
+	/// A concept of the 2012 ACM Computing Classification System
+	Ccs2012(CcsConcept),
+}
+
+impl AcmClass {
+	/// Parses the classes listed in an `acm-class` field.
+	///
+	/// Classes are separated by semicolons; 1998 codes may also be separated by commas or
+	/// whitespace, while CCS 2012 concepts (which may contain both) may not.
+	pub fn parse_list(s: &str) -> Result<Vec<Self>, AcmError<'_>> {
+		let mut classes = Vec::new();
+		for part in s.split(';').map(str::trim).filter(|part| !part.is_empty()) {
+			if part.contains(['~', '→']) {
+				classes.push(Self::Ccs2012(CcsConcept::try_from(part)?));
+				continue;
+			}
+			for code in part.split([',', ' ']).filter(|code| !code.is_empty()) {
+				classes.push(Self::Acm1998(Acm1998Class::try_from(code)?));
+			}
+		}
+		Ok(classes)
+	}
+
+	/// The `cs` categories that most closely correspond to the class.
+	#[must_use]
+	pub fn categories(&self) -> Vec<CategoryId> {
+		match self {
+			Self::Acm1998(class) => class.categories(),
+			Self::Ccs2012(concept) => concept.categories(),
+		}
+	}
+}
+
+impl<'a> TryFrom<&'a str> for AcmClass {
+	type Error = AcmError<'a>;
+
+	fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+		match s.contains(['~', '→']) {
+			true => CcsConcept::try_from(s).map(Self::Ccs2012),
+			false => Acm1998Class::try_from(s).map(Self::Acm1998),
+		}
+	}
+}
+
+impl Display for AcmClass {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Acm1998(class) => class.fmt(f),
+			Self::Ccs2012(concept) => concept.fmt(f),
+		}
+	}
+}
+
+/// Collects the subjects with the greatest depth, in canonical order.
+fn most_specific(matches: impl Iterator<Item = (usize, CsSubject)>) -> Vec<CategoryId> {
+	let mut best = 0;
+	let mut subjects = Vec::new();
+	for (depth, subject) in matches {
+		if depth > best || subjects.is_empty() {
+			best = depth;
+			subjects.clear();
+		}
+		if depth == best && !subjects.contains(&subject) {
+			subjects.push(subject);
+		}
+	}
+	subjects.into_iter().map(CategoryId::from).collect()
+}
+
+impl CsSubject {
+	/// The 1998 ACM classes that arXiv's description of the subject class lists as roughly
+	/// corresponding to it, if any.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Acm1998Class, CsSubject};
+	///
+	/// assert_eq!(CsSubject::Se.acm_classes(), [Acm1998Class::try_from("D.2").unwrap()]);
+	/// ```
+	#[must_use]
+	pub const fn acm_classes(&self) -> &'static [Acm1998Class] {
+		CS_CLASSES[*self as usize].1
+	}
+
+	/// The CCS 2012 concept paths that roughly correspond to the subject class, if any.
+	#[must_use]
+	pub const fn ccs_concepts(&self) -> &'static [&'static [&'static str]] {
+		CS_CLASSES[*self as usize].2
+	}
+}
+
+impl CategoryId {
+	/// The 1998 ACM classes that roughly correspond to the category, which are only known for
+	/// categories of the [`Archive::Cs`](crate::Archive::Cs) archive.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Acm1998Class, CategoryId};
+	///
+	/// let category = CategoryId::try_from("cs.CL").unwrap();
+	/// assert_eq!(category.acm_classes(), [Acm1998Class::try_from("I.2.7").unwrap()]);
+	/// ```
+	#[must_use]
+	pub const fn acm_classes(&self) -> &'static [Acm1998Class] {
+		match self.subject() {
+			Some(Subject::Cs(subject)) => subject.acm_classes(),
+			_ => &[],
+		}
+	}
+
+	/// The CCS 2012 concept paths that roughly correspond to the category, which are only known
+	/// for categories of the [`Archive::Cs`](crate::Archive::Cs) archive.
+	#[must_use]
+	pub const fn ccs_concepts(&self) -> &'static [&'static [&'static str]] {
+		match self.subject() {
+			Some(Subject::Cs(subject)) => subject.ccs_concepts(),
+			_ => &[],
+		}
+	}
+}
+
+/// The names of the top-level sections of the 1998 ACM Computing Classification System
+const SECTIONS: [&str; 11] = [
+	"General Literature",
+	"Hardware",
+	"Computer Systems Organization",
+	"Software",
+	"Data",
+	"Theory of Computation",
+	"Mathematics of Computing",
+	"Information Systems",
+	"Computing Methodologies",
+	"Computer Applications",
+	"Computing Milieux",
+];
+
+/// The top-level concepts of the 2012 ACM Computing Classification System
+const CONCEPTS: [&str; 13] = [
+	"General and reference",
+	"Hardware",
+	"Computer systems organization",
+	"Networks",
+	"Software and its engineering",
+	"Theory of computation",
+	"Mathematics of computing",
+	"Information systems",
+	"Security and privacy",
+	"Human-centered computing",
+	"Computing methodologies",
+	"Applied computing",
+	"Social and professional topics",
+];
+
+type CsClasses = (CsSubject, &'static [Acm1998Class], &'static [&'static [&'static str]]);
+
+/// The ACM classes of each `cs` subject class, in the same order as [`CsSubject::ALL`]
+///
+/// The 1998 codes are taken from arXiv's category descriptions.
+const CS_CLASSES: &[CsClasses] = {
+	use AcmLevel::Num;
+	use CsSubject::*;
+	const fn sub(section: u8, subsection: u8) -> Acm1998Class {
+		Acm1998Class::new(section, Some(Num(subsection)), None)
+	}
+	const fn top(section: u8, subsection: u8, topic: u8) -> Acm1998Class {
+		Acm1998Class::new(section, Some(Num(subsection)), Some(Num(topic)))
+	}
+	&[
+		(
+			Ai,
+			&[
+				top(b'I', 2, 0),
+				top(b'I', 2, 1),
+				top(b'I', 2, 3),
+				top(b'I', 2, 4),
+				top(b'I', 2, 8),
+				top(b'I', 2, 11),
+			],
+			&[&["Computing methodologies", "Artificial intelligence"]],
+		),
+		(
+			Ar,
+			&[sub(b'C', 0), sub(b'C', 1), sub(b'C', 5)],
+			&[
+				&["Hardware"],
+				&["Computer systems organization", "Architectures"],
+			],
+		),
+		(
+			Cc,
+			&[sub(b'F', 1), top(b'F', 2, 3), top(b'F', 4, 3)],
+			&[&[
+				"Theory of computation",
+				"Computational complexity and cryptography",
+			]],
+		),
+		(Ce, &[sub(b'J', 2)], &[&["Applied computing"]]),
+		(
+			Cg,
+			&[top(b'I', 3, 5), top(b'F', 2, 2)],
+			&[&[
+				"Theory of computation",
+				"Randomness, geometry and discrete structures",
+				"Computational geometry",
+			]],
+		),
+		(
+			Cl,
+			&[top(b'I', 2, 7)],
+			&[&[
+				"Computing methodologies",
+				"Artificial intelligence",
+				"Natural language processing",
+			]],
+		),
+		(Cr, &[top(b'D', 4, 6), sub(b'E', 3)], &[&["Security and privacy"]]),
+		(
+			Cv,
+			&[top(b'I', 2, 10), sub(b'I', 4), sub(b'I', 5)],
+			&[&[
+				"Computing methodologies",
+				"Artificial intelligence",
+				"Computer vision",
+			]],
+		),
+		(
+			Cy,
+			&[
+				sub(b'K', 0),
+				sub(b'K', 2),
+				sub(b'K', 3),
+				sub(b'K', 4),
+				sub(b'K', 5),
+				sub(b'K', 7),
+			],
+			&[&["Social and professional topics"]],
+		),
+		(
+			Db,
+			&[sub(b'E', 2), sub(b'E', 5), sub(b'H', 0), sub(b'H', 2)],
+			&[&["Information systems", "Data management systems"]],
+		),
+		(
+			Dc,
+			&[
+				top(b'C', 1, 2),
+				top(b'C', 1, 4),
+				top(b'C', 2, 4),
+				top(b'D', 1, 3),
+				top(b'D', 4, 5),
+				top(b'D', 4, 7),
+				sub(b'E', 1),
+			],
+			&[
+				&[
+					"Computing methodologies",
+					"Parallel computing methodologies",
+				],
+				&[
+					"Computer systems organization",
+					"Architectures",
+					"Distributed architectures",
+				],
+			],
+		),
+		(
+			Dl,
+			&[
+				top(b'H', 3, 5),
+				top(b'H', 3, 6),
+				top(b'H', 3, 7),
+				sub(b'I', 7),
+			],
+			&[&[
+				"Information systems",
+				"Information systems applications",
+				"Digital libraries and archives",
+			]],
+		),
+		(
+			Dm,
+			&[sub(b'G', 2), sub(b'G', 3)],
+			&[&["Mathematics of computing", "Discrete mathematics"]],
+		),
+		(
+			Ds,
+			&[sub(b'E', 1), sub(b'E', 2), top(b'F', 2, 0), top(b'F', 2, 2)],
+			&[&["Theory of computation", "Design and analysis of algorithms"]],
+		),
+		(Et, &[], &[&["Hardware", "Emerging technologies"]]),
+		(
+			Fl,
+			&[top(b'F', 1, 1), top(b'F', 4, 3)],
+			&[&[
+				"Theory of computation",
+				"Formal languages and automata theory",
+			]],
+		),
+		(Gl, &[sub(b'A', 0), sub(b'A', 1), sub(b'A', 2)], &[&["General and reference"]]),
+		(Gr, &[sub(b'I', 3)], &[&["Computing methodologies", "Computer graphics"]]),
+		(
+			Gt,
+			&[],
+			&[&[
+				"Theory of computation",
+				"Theory and algorithms for application domains",
+				"Algorithmic game theory and mechanism design",
+			]],
+		),
+		(Hc, &[top(b'H', 1, 2), sub(b'H', 5)], &[&["Human-centered computing"]]),
+		(
+			Ir,
+			&[
+				top(b'H', 3, 0),
+				top(b'H', 3, 1),
+				top(b'H', 3, 2),
+				top(b'H', 3, 3),
+				top(b'H', 3, 4),
+			],
+			&[&["Information systems", "Information retrieval"]],
+		),
+		(
+			It,
+			&[sub(b'E', 4), top(b'H', 1, 1)],
+			&[&["Mathematics of computing", "Information theory"]],
+		),
+		(Lg, &[top(b'I', 2, 6)], &[&["Computing methodologies", "Machine learning"]]),
+		(
+			Lo,
+			&[
+				top(b'D', 2, 4),
+				top(b'F', 3, 1),
+				top(b'F', 4, 0),
+				top(b'F', 4, 1),
+				top(b'F', 4, 2),
+			],
+			&[&["Theory of computation", "Logic"]],
+		),
+		(
+			Ma,
+			&[top(b'I', 2, 11)],
+			&[&[
+				"Computing methodologies",
+				"Artificial intelligence",
+				"Distributed artificial intelligence",
+			]],
+		),
+		(
+			Mm,
+			&[top(b'H', 5, 1)],
+			&[&[
+				"Information systems",
+				"Information systems applications",
+				"Multimedia information systems",
+			]],
+		),
+		(Ms, &[sub(b'G', 4)], &[&["Mathematics of computing", "Mathematical software"]]),
+		(
+			Na,
+			&[sub(b'G', 1)],
+			&[&[
+				"Mathematics of computing",
+				"Mathematical analysis",
+				"Numerical analysis",
+			]],
+		),
+		(
+			Ne,
+			&[top(b'C', 1, 3), top(b'I', 2, 6), sub(b'I', 5)],
+			&[&[
+				"Computing methodologies",
+				"Machine learning",
+				"Machine learning approaches",
+				"Bio-inspired approaches",
+			]],
+		),
+		(Ni, &[sub(b'C', 2)], &[&["Networks"]]),
+		(Oh, &[], &[]),
+		(
+			Os,
+			&[sub(b'D', 4)],
+			&[&[
+				"Software and its engineering",
+				"Software organization and properties",
+				"Contextual software domains",
+				"Operating systems",
+			]],
+		),
+		(
+			Pf,
+			&[top(b'D', 4, 8), top(b'K', 6, 2)],
+			&[&[
+				"General and reference",
+				"Cross-computing tools and techniques",
+				"Performance",
+			]],
+		),
+		(
+			Pl,
+			&[sub(b'D', 1), sub(b'D', 3)],
+			&[&[
+				"Software and its engineering",
+				"Software notations and tools",
+				"General programming languages",
+			]],
+		),
+		(
+			Ro,
+			&[top(b'I', 2, 9)],
+			&[&[
+				"Computer systems organization",
+				"Embedded and cyber-physical systems",
+				"Robotics",
+			]],
+		),
+		(
+			Sc,
+			&[sub(b'I', 1)],
+			&[&[
+				"Computing methodologies",
+				"Symbolic and algebraic manipulation",
+			]],
+		),
+		(
+			Sd,
+			&[top(b'H', 5, 5)],
+			&[&[
+				"Applied computing",
+				"Arts and humanities",
+				"Sound and music computing",
+			]],
+		),
+		(Se, &[sub(b'D', 2)], &[&["Software and its engineering"]]),
+		(
+			Si,
+			&[],
+			&[&[
+				"Human-centered computing",
+				"Collaborative and social computing",
+			]],
+		),
+		(
+			Sy,
+			&[],
+			&[&[
+				"Computer systems organization",
+				"Embedded and cyber-physical systems",
+			]],
+		),
+	]
+};
+
+#[cfg(test)]
+mod tests {
+	use super::CS_CLASSES;
+	use crate::{Acm1998Class, AcmClass, AcmError, AcmLevel, CategoryId, CcsConcept, CsSubject};
+
+	fn categories(ids: &[&str]) -> Vec<CategoryId> {
+		ids.iter()
+			.map(|id| CategoryId::try_from(*id).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn parse_1998() {
+		let class = Acm1998Class::try_from("I.2.m").unwrap();
+		assert_eq!(class.subsection(), Some(AcmLevel::Num(2)));
+		assert_eq!(class.topic(), Some(AcmLevel::Misc));
+		assert_eq!(Acm1998Class::try_from("K").unwrap().subsection(), None);
+
+		for s in ["I.2.11", "F.2.2", "D.3", "K", "I.2.m"] {
+			assert_eq!(Acm1998Class::try_from(s).unwrap().to_string(), s);
+		}
+		for s in ["L.1", "I.22", "I.2.100", "I.2.6.1", "i.2.6", "I.", "I.2."] {
+			assert_eq!(Acm1998Class::try_from(s), Err(AcmError::InvalidClass(s)));
+		}
+	}
+
+	#[test]
+	fn parse_ccs() {
+		let concept = CcsConcept::try_from("Theory of computation ~ Logic").unwrap();
+		assert_eq!(concept.path(), ["Theory of computation", "Logic"]);
+		assert_eq!(concept.to_string(), "Theory of computation~Logic");
+
+		assert_eq!(CcsConcept::try_from("Networks~"), Err(AcmError::InvalidClass("Networks~")));
+		assert_eq!(
+			CcsConcept::try_from("Cooking~Baking"),
+			Err(AcmError::UnknownConcept("Cooking~Baking"))
+		);
+	}
+
+	#[test]
+	fn parse_list() {
+		let classes =
+			AcmClass::parse_list("I.2.6, I.2.7 F.2.2; Information systems~Information retrieval")
+				.unwrap();
+		let shown: Vec<String> = classes.iter().map(ToString::to_string).collect();
+		assert_eq!(
+			shown,
+			[
+				"I.2.6",
+				"I.2.7",
+				"F.2.2",
+				"Information systems~Information retrieval"
+			]
+		);
+
+		assert_eq!(AcmClass::parse_list(""), Ok(Vec::new()));
+		assert_eq!(AcmClass::parse_list("I.2.6, 68T05"), Err(AcmError::InvalidClass("68T05")));
+	}
+
+	#[test]
+	fn table_order() {
+		let subjects: Vec<_> = CS_CLASSES.iter().map(|(subject, ..)| *subject).collect();
+		assert_eq!(subjects, CsSubject::ALL);
+	}
+
+	#[test]
+	fn mapping_is_bidirectional() {
+		for subject in CsSubject::ALL {
+			let category = CategoryId::from(*subject);
+			for class in subject.acm_classes() {
+				assert!(class.categories().contains(&category), "{class}");
+			}
+			for path in subject.ccs_concepts() {
+				let concept = CcsConcept::try_from(path.join("~").as_str()).unwrap();
+				assert!(concept.categories().contains(&category), "{concept}");
+			}
+		}
+	}
+
+	#[test]
+	fn categories_prefer_specific_class() {
+		let class = |s| Acm1998Class::try_from(s).unwrap().categories();
+		assert_eq!(class("I.2.5"), []);
+		assert_eq!(class("I.2.6"), categories(&["cs.LG", "cs.NE"]));
+		assert_eq!(class("H.5.2"), categories(&["cs.HC"]));
+		assert_eq!(class("I.4.8"), categories(&["cs.CV"]));
+		assert_eq!(class("F.2.2"), categories(&["cs.CG", "cs.DS"]));
+
+		let concept = |s| CcsConcept::try_from(s).unwrap().categories();
+		assert_eq!(
+			concept("Computing methodologies~Artificial intelligence~Planning"),
+			categories(&["cs.AI"])
+		);
+		assert_eq!(
+			concept("Computing methodologies~Artificial intelligence~Computer vision~Tracking"),
+			categories(&["cs.CV"])
+		);
+		assert_eq!(concept("Hardware~Robustness"), categories(&["cs.AR"]));
+		assert_eq!(concept("Applied computing~Law"), categories(&["cs.CE"]));
+	}
+}
@@ -0,0 +1,91 @@
+use jiff::civil::Date;
+
+/// The period of time during which an archive or category accepted new submissions
+///
+/// # Examples
+/// ```
+/// use arxiv::Archive;
+/// use jiff::civil::date;
+///
+/// let period = Archive::ChaoDyn.active_period();
+/// assert!(period.contains(date(1995, 6, 1)));
+/// assert!(!period.contains(date(2001, 6, 1)));
+/// assert!(period.is_retired());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ActivePeriod {
+	/// The first day on which submissions were accepted
+	pub start: Date,
+	/// The first day on which submissions were no longer accepted, if the period has ended
+	pub end: Option<Date>,
+}
+
+impl ActivePeriod {
+	/// Creates a period from its first day, and the first day after it has ended (if any).
+	#[inline]
+	pub const fn new(start: Date, end: Option<Date>) -> Self {
+		Self { start, end }
+	}
+
+	/// Creates a period that started on the given day and has not ended.
+	#[inline]
+	pub const fn since(start: Date) -> Self {
+		Self::new(start, None)
+	}
+
+	/// Checks if the period has ended.
+	#[must_use]
+	#[inline]
+	pub const fn is_retired(&self) -> bool {
+		self.end.is_some()
+	}
+
+	/// Checks if the date falls within the period.
+	#[must_use]
+	pub fn contains(&self, date: Date) -> bool {
+		self.start <= date && self.end.is_none_or(|end| date < end)
+	}
+
+	/// Checks if any day of the given month falls within the period.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::ActivePeriod;
+	/// use jiff::civil::date;
+	///
+	/// let period = ActivePeriod::since(date(2017, 9, 15));
+	/// assert!(period.contains_month(2017, 9));
+	/// assert!(!period.contains_month(2017, 8));
+	/// ```
+	#[must_use]
+	pub fn contains_month(&self, year: i16, month: i8) -> bool {
+		let Ok(first) = Date::new(year, month, 1) else {
+			return false;
+		};
+		self.end.is_none_or(|end| first < end) && self.start <= first.last_of_month()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::ActivePeriod;
+	use jiff::civil::date;
+
+	#[test]
+	fn contains() {
+		let period = ActivePeriod::new(date(1993, 1, 1), Some(date(2000, 1, 1)));
+		assert!(period.contains(date(1993, 1, 1)));
+		assert!(period.contains(date(1999, 12, 31)));
+		assert!(!period.contains(date(2000, 1, 1)));
+		assert!(!period.contains(date(1992, 12, 31)));
+	}
+
+	#[test]
+	fn contains_month() {
+		let period = ActivePeriod::new(date(1993, 1, 15), Some(date(2000, 1, 1)));
+		assert!(period.contains_month(1993, 1));
+		assert!(period.contains_month(1999, 12));
+		assert!(!period.contains_month(2000, 1));
+		assert!(!period.contains_month(1999, 13));
+	}
+}
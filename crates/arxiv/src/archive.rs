@@ -1,11 +1,56 @@
+use crate::suggest::{edit_distance, max_distance};
+use crate::{
+	ActivePeriod, AstroPhSubject, CategoryId, CondMatSubject, CsSubject, EconSubject, EessSubject,
+	MathSubject, NlinSubject, PhysicsSubject, QBioSubject, QFinSubject, StatSubject, Subject,
+};
+use jiff::civil::{Date, date};
+use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
 
+/// An error that can occur when parsing arXiv archive identifiers
+///
+/// # Examples
+/// ```
+/// use arxiv::Archive;
+/// use std::str::FromStr;
+///
+/// let error = Archive::from_str("astroph").unwrap_err();
+/// assert_eq!(error.suggestions(), vec![Archive::AstroPh]);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArchiveError {
+	/// The string is not a known archive identifier
+	InvalidArchive(String),
+}
+
+impl ArchiveError {
+	/// Archives with an identifier close to the invalid string, which the user may have meant
+	#[must_use]
+	pub fn suggestions(&self) -> Vec<Archive> {
+		match self {
+			Self::InvalidArchive(s) => Archive::suggest(s),
+		}
+	}
+}
+
+impl Error for ArchiveError {}
+
+impl Display for ArchiveError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::InvalidArchive(s) => write!(f, "Invalid arXiv archive identifier: {s}"),
+		}
+	}
+}
+
 /// A collection of publications that relate under the same field of study
 ///
 /// Valid archive identifiers are listed under the official website's page for [category taxonomy][arxiv-cat].
 ///
 /// [arxiv-cat]: <https://arxiv.org/category_taxonomy>
+#[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Archive {
 	/// Astrophysics (link on [arXiv])
@@ -107,51 +152,288 @@ pub enum Archive {
 	///
 	/// [arXiv]: <https://arxiv.org/archive/stat>
 	Stat,
+
+	/// Accelerator Physics (legacy archive, subsumed into `physics.acc-ph`)
+	AccPhys,
+
+	/// Adaptation, Noise, and Self-Organizing Systems (legacy archive, subsumed into `nlin.AO`)
+	AdapOrg,
+
+	/// Algebraic Geometry (legacy archive, subsumed into `math.AG`)
+	AlgGeom,
+
+	/// Atmospheric-Oceanic Sciences (legacy archive, subsumed into `physics.ao-ph`)
+	AoSci,
+
+	/// Atomic, Molecular and Optical Physics (legacy archive, subsumed into `physics.atom-ph`)
+	AtomPh,
+
+	/// Bayesian Analysis (legacy archive, subsumed into `physics.data-an`)
+	BayesAn,
+
+	/// Chaotic Dynamics (legacy archive, subsumed into `nlin.CD`)
+	ChaoDyn,
+
+	/// Chemical Physics (legacy archive, subsumed into `physics.chem-ph`)
+	ChemPh,
+
+	/// Computation and Language (legacy archive, subsumed into `cs.CL`)
+	CmpLg,
+
+	/// Cellular Automata and Lattice Gases (legacy archive, subsumed into `nlin.CG`)
+	CompGas,
+
+	/// Differential Geometry (legacy archive, subsumed into `math.DG`)
+	DgGa,
+
+	/// Functional Analysis (legacy archive, subsumed into `math.FA`)
+	FunctAn,
+
+	/// Materials Theory (legacy archive, subsumed into `cond-mat.mtrl-sci`)
+	MtrlTh,
+
+	/// Pattern Formation and Solitons (legacy archive, subsumed into `nlin.PS`)
+	PattSol,
+
+	/// Plasma Physics (legacy archive, subsumed into `physics.plasm-ph`)
+	PlasmPh,
+
+	/// Quantum Algebra and Topology (legacy archive, subsumed into `math.QA`)
+	QAlg,
+
+	/// Exactly Solvable and Integrable Systems (legacy archive, subsumed into `nlin.SI`)
+	SolvInt,
+
+	/// Superconductivity (legacy archive, subsumed into `cond-mat.supr-con`)
+	SuprCon,
 }
 
 impl Archive {
+	/// All archives, in canonical order
+	pub const ALL: &'static [Self] = &[
+		Self::AstroPh,
+		Self::CondMat,
+		Self::Cs,
+		Self::Econ,
+		Self::Eess,
+		Self::GrQc,
+		Self::HepEx,
+		Self::HepLat,
+		Self::HepPh,
+		Self::HepTh,
+		Self::MathPh,
+		Self::Math,
+		Self::Nlin,
+		Self::NuclEx,
+		Self::NuclTh,
+		Self::Physics,
+		Self::QBio,
+		Self::QFin,
+		Self::QuantPh,
+		Self::Stat,
+		Self::AccPhys,
+		Self::AdapOrg,
+		Self::AlgGeom,
+		Self::AoSci,
+		Self::AtomPh,
+		Self::BayesAn,
+		Self::ChaoDyn,
+		Self::ChemPh,
+		Self::CmpLg,
+		Self::CompGas,
+		Self::DgGa,
+		Self::FunctAn,
+		Self::MtrlTh,
+		Self::PattSol,
+		Self::PlasmPh,
+		Self::QAlg,
+		Self::SolvInt,
+		Self::SuprCon,
+	];
+
 	/// Checks if the archive contains any nested subjects.
 	///
 	/// ```
 	/// use arxiv::Archive;
 	///
-	/// assert!(Archive::GrQc.contains_subjects());
+	/// assert!(Archive::AstroPh.contains_subjects());
+	/// assert!(!Archive::GrQc.contains_subjects());
 	/// ```
+	#[must_use]
 	pub const fn contains_subjects(&self) -> bool {
-		matches!(
-			self,
+		!self.subjects().is_empty()
+	}
+
+	/// The subject classes nested under the archive, in canonical order.
+	///
+	/// Archives that are not divided into subject classes return an empty slice.
+	///
+	/// ```
+	/// use arxiv::{Archive, EconSubject, Subject};
+	///
+	/// assert_eq!(Archive::Econ.subjects().len(), 3);
+	/// assert_eq!(Archive::Econ.subjects()[0], Subject::Econ(EconSubject::Em));
+	/// assert!(Archive::HepTh.subjects().is_empty());
+	/// ```
+	#[must_use]
+	pub const fn subjects(&self) -> &'static [Subject] {
+		match self {
+			Self::AstroPh => AstroPhSubject::SUBJECTS,
+			Self::CondMat => CondMatSubject::SUBJECTS,
+			Self::Cs => CsSubject::SUBJECTS,
+			Self::Econ => EconSubject::SUBJECTS,
+			Self::Eess => EessSubject::SUBJECTS,
+			Self::Math => MathSubject::SUBJECTS,
+			Self::Nlin => NlinSubject::SUBJECTS,
+			Self::Physics => PhysicsSubject::SUBJECTS,
+			Self::QBio => QBioSubject::SUBJECTS,
+			Self::QFin => QFinSubject::SUBJECTS,
+			Self::Stat => StatSubject::SUBJECTS,
 			Self::GrQc
-				| Self::HepEx
-				| Self::HepLat
-				| Self::HepPh
-				| Self::HepTh
-				| Self::MathPh
-				| Self::NuclEx
-				| Self::NuclTh
-				| Self::QuantPh
-		)
+			| Self::HepEx
+			| Self::HepLat
+			| Self::HepPh
+			| Self::HepTh
+			| Self::MathPh
+			| Self::NuclEx
+			| Self::NuclTh
+			| Self::QuantPh
+			| Self::AccPhys
+			| Self::AdapOrg
+			| Self::AlgGeom
+			| Self::AoSci
+			| Self::AtomPh
+			| Self::BayesAn
+			| Self::ChaoDyn
+			| Self::ChemPh
+			| Self::CmpLg
+			| Self::CompGas
+			| Self::DgGa
+			| Self::FunctAn
+			| Self::MtrlTh
+			| Self::PattSol
+			| Self::PlasmPh
+			| Self::QAlg
+			| Self::SolvInt
+			| Self::SuprCon => &[],
+		}
 	}
 
-	/// Converts the article identifier to a URL where the abstract page is.
+	/// The period during which the archive accepted new submissions.
+	///
+	/// Dates are accurate to the month in which arXiv introduced or retired the archive.
 	///
 	/// ```
 	/// use arxiv::Archive;
-	/// use url::Url;
+	/// use jiff::civil::date;
 	///
-	/// let id = Archive::AstroPh;
-	/// let url = Url::from(id);
-	/// assert_eq!(url.to_string(), "https://arxiv.org/archive/astro-ph");
+	/// assert_eq!(Archive::Econ.active_period().start, date(2017, 9, 1));
+	/// assert_eq!(Archive::CmpLg.active_period().end, Some(date(1998, 9, 1)));
 	/// ```
-	#[cfg(feature = "url")]
-	#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
-	pub fn as_url(&self) -> url::Url {
-		url::Url::from(*self)
+	#[must_use]
+	pub const fn active_period(&self) -> ActivePeriod {
+		match self {
+			Self::AstroPh => ActivePeriod::since(date(1992, 4, 1)),
+			Self::CondMat => ActivePeriod::since(date(1992, 4, 1)),
+			Self::Cs => ActivePeriod::since(date(1998, 9, 1)),
+			Self::Econ => ActivePeriod::since(date(2017, 9, 1)),
+			Self::Eess => ActivePeriod::since(date(2017, 9, 1)),
+			Self::GrQc => ActivePeriod::since(date(1992, 7, 1)),
+			Self::HepEx => ActivePeriod::since(date(1994, 4, 1)),
+			Self::HepLat => ActivePeriod::since(date(1992, 2, 1)),
+			Self::HepPh => ActivePeriod::since(date(1992, 3, 1)),
+			Self::HepTh => ActivePeriod::since(date(1991, 8, 1)),
+			Self::MathPh => ActivePeriod::since(date(1996, 9, 1)),
+			Self::Math => ActivePeriod::since(date(1992, 1, 1)),
+			Self::Nlin => ActivePeriod::since(date(2000, 1, 1)),
+			Self::NuclEx => ActivePeriod::since(date(1994, 12, 1)),
+			Self::NuclTh => ActivePeriod::since(date(1992, 10, 1)),
+			Self::Physics => ActivePeriod::since(date(1996, 10, 1)),
+			Self::QBio => ActivePeriod::since(date(2003, 9, 1)),
+			Self::QFin => ActivePeriod::since(date(2008, 12, 1)),
+			Self::QuantPh => ActivePeriod::since(date(1994, 12, 1)),
+			Self::Stat => ActivePeriod::since(date(2007, 4, 1)),
+			Self::AccPhys => ActivePeriod::new(date(1994, 11, 1), Some(date(1996, 10, 1))),
+			Self::AdapOrg => ActivePeriod::new(date(1993, 3, 1), Some(date(2000, 1, 1))),
+			Self::AlgGeom => ActivePeriod::new(date(1992, 2, 1), Some(date(1998, 1, 1))),
+			Self::AoSci => ActivePeriod::new(date(1995, 2, 1), Some(date(1996, 10, 1))),
+			Self::AtomPh => ActivePeriod::new(date(1995, 9, 1), Some(date(1996, 10, 1))),
+			Self::BayesAn => ActivePeriod::new(date(1995, 6, 1), Some(date(1996, 12, 1))),
+			Self::ChaoDyn => ActivePeriod::new(date(1993, 1, 1), Some(date(2000, 1, 1))),
+			Self::ChemPh => ActivePeriod::new(date(1994, 3, 1), Some(date(1996, 10, 1))),
+			Self::CmpLg => ActivePeriod::new(date(1994, 4, 1), Some(date(1998, 9, 1))),
+			Self::CompGas => ActivePeriod::new(date(1993, 2, 1), Some(date(2000, 1, 1))),
+			Self::DgGa => ActivePeriod::new(date(1994, 6, 1), Some(date(1998, 1, 1))),
+			Self::FunctAn => ActivePeriod::new(date(1993, 1, 1), Some(date(1998, 1, 1))),
+			Self::MtrlTh => ActivePeriod::new(date(1994, 11, 1), Some(date(1996, 10, 1))),
+			Self::PattSol => ActivePeriod::new(date(1993, 2, 1), Some(date(2000, 1, 1))),
+			Self::PlasmPh => ActivePeriod::new(date(1994, 11, 1), Some(date(1996, 10, 1))),
+			Self::QAlg => ActivePeriod::new(date(1994, 12, 1), Some(date(1998, 1, 1))),
+			Self::SolvInt => ActivePeriod::new(date(1993, 2, 1), Some(date(2000, 1, 1))),
+			Self::SuprCon => ActivePeriod::new(date(1994, 11, 1), Some(date(1996, 10, 1))),
+		}
 	}
-}
 
-impl Display for Archive {
-	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-		f.write_str(match self {
+	/// Checks if the archive accepted new submissions on the given date.
+	///
+	/// ```
+	/// use arxiv::Archive;
+	/// use jiff::civil::date;
+	///
+	/// assert!(Archive::Eess.valid_at(date(2018, 1, 1)));
+	/// assert!(!Archive::Eess.valid_at(date(2016, 1, 1)));
+	/// ```
+	#[must_use]
+	pub fn valid_at(&self, date: Date) -> bool {
+		self.active_period().contains(date)
+	}
+
+	/// Checks if the archive is a legacy archive, which has since been subsumed into a category of
+	/// another archive.
+	#[must_use]
+	pub fn is_legacy(&self) -> bool {
+		self.subsumed_into().is_some()
+	}
+
+	/// The category that a legacy archive was subsumed into, if any.
+	///
+	/// ```
+	/// use arxiv::{Archive, CategoryId};
+	///
+	/// let category = Archive::ChaoDyn.subsumed_into().unwrap();
+	/// assert_eq!(category.to_string(), "nlin.CD");
+	/// assert_eq!(Archive::Nlin.subsumed_into(), None);
+	/// ```
+	#[must_use]
+	pub fn subsumed_into(&self) -> Option<CategoryId> {
+		let subject = match self {
+			Self::AccPhys => Subject::Physics(PhysicsSubject::AccPh),
+			Self::AdapOrg => Subject::Nlin(NlinSubject::Ao),
+			Self::AlgGeom => Subject::Math(MathSubject::Ag),
+			Self::AoSci => Subject::Physics(PhysicsSubject::AoPh),
+			Self::AtomPh => Subject::Physics(PhysicsSubject::AtomPh),
+			Self::BayesAn => Subject::Physics(PhysicsSubject::DataAn),
+			Self::ChaoDyn => Subject::Nlin(NlinSubject::Cd),
+			Self::ChemPh => Subject::Physics(PhysicsSubject::ChemPh),
+			Self::CmpLg => Subject::Cs(CsSubject::Cl),
+			Self::CompGas => Subject::Nlin(NlinSubject::Cg),
+			Self::DgGa => Subject::Math(MathSubject::Dg),
+			Self::FunctAn => Subject::Math(MathSubject::Fa),
+			Self::MtrlTh => Subject::CondMat(CondMatSubject::MtrlSci),
+			Self::PattSol => Subject::Nlin(NlinSubject::Ps),
+			Self::PlasmPh => Subject::Physics(PhysicsSubject::PlasmPh),
+			Self::QAlg => Subject::Math(MathSubject::Qa),
+			Self::SolvInt => Subject::Nlin(NlinSubject::Si),
+			Self::SuprCon => Subject::CondMat(CondMatSubject::SuprCon),
+			_ => return None,
+		};
+		Some(CategoryId::from(subject))
+	}
+
+	/// The identifier of the archive, as used by arXiv (e.g. `astro-ph`)
+	#[must_use]
+	pub const fn as_str(&self) -> &'static str {
+		match self {
 			Self::AstroPh => "astro-ph",
 			Self::CondMat => "cond-mat",
 			Self::Cs => "cs",
@@ -172,14 +454,102 @@ impl Display for Archive {
 			Self::QFin => "q-fin",
 			Self::QuantPh => "quant-ph",
 			Self::Stat => "stat",
-		})
+			Self::AccPhys => "acc-phys",
+			Self::AdapOrg => "adap-org",
+			Self::AlgGeom => "alg-geom",
+			Self::AoSci => "ao-sci",
+			Self::AtomPh => "atom-ph",
+			Self::BayesAn => "bayes-an",
+			Self::ChaoDyn => "chao-dyn",
+			Self::ChemPh => "chem-ph",
+			Self::CmpLg => "cmp-lg",
+			Self::CompGas => "comp-gas",
+			Self::DgGa => "dg-ga",
+			Self::FunctAn => "funct-an",
+			Self::MtrlTh => "mtrl-th",
+			Self::PattSol => "patt-sol",
+			Self::PlasmPh => "plasm-ph",
+			Self::QAlg => "q-alg",
+			Self::SolvInt => "solv-int",
+			Self::SuprCon => "supr-con",
+		}
 	}
-}
 
-impl FromStr for Archive {
-	type Err = ();
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		Ok(match s {
+	/// The human-readable name of the archive
+	///
+	/// ```
+	/// use arxiv::Archive;
+	///
+	/// assert_eq!(Archive::HepLat.name(), "High Energy Physics - Lattice");
+	/// ```
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		match self {
+			Self::AstroPh => "Astrophysics",
+			Self::CondMat => "Condensed Matter",
+			Self::Cs => "Computer Science",
+			Self::Econ => "Economics",
+			Self::Eess => "Electrical Engineering and Systems Science",
+			Self::GrQc => "General Relativity and Quantum Cosmology",
+			Self::HepEx => "High Energy Physics - Experiment",
+			Self::HepLat => "High Energy Physics - Lattice",
+			Self::HepPh => "High Energy Physics - Phenomenology",
+			Self::HepTh => "High Energy Physics - Theory",
+			Self::MathPh => "Mathematical Physics",
+			Self::Math => "Mathematics",
+			Self::Nlin => "Nonlinear Sciences",
+			Self::NuclEx => "Nuclear Experiment",
+			Self::NuclTh => "Nuclear Theory",
+			Self::Physics => "Physics",
+			Self::QBio => "Quantitative Biology",
+			Self::QFin => "Quantitative Finance",
+			Self::QuantPh => "Quantum Physics",
+			Self::Stat => "Statistics",
+			Self::AccPhys => "Accelerator Physics",
+			Self::AdapOrg => "Adaptation, Noise, and Self-Organizing Systems",
+			Self::AlgGeom => "Algebraic Geometry",
+			Self::AoSci => "Atmospheric-Oceanic Sciences",
+			Self::AtomPh => "Atomic, Molecular and Optical Physics",
+			Self::BayesAn => "Bayesian Analysis",
+			Self::ChaoDyn => "Chaotic Dynamics",
+			Self::ChemPh => "Chemical Physics",
+			Self::CmpLg => "Computation and Language",
+			Self::CompGas => "Cellular Automata and Lattice Gases",
+			Self::DgGa => "Differential Geometry",
+			Self::FunctAn => "Functional Analysis",
+			Self::MtrlTh => "Materials Theory",
+			Self::PattSol => "Pattern Formation and Solitons",
+			Self::PlasmPh => "Plasma Physics",
+			Self::QAlg => "Quantum Algebra and Topology",
+			Self::SolvInt => "Exactly Solvable and Integrable Systems",
+			Self::SuprCon => "Superconductivity",
+		}
+	}
+
+	/// Suggests archives with an identifier close to the given string, ordered from the
+	/// closest match. Letter case is ignored.
+	///
+	/// ```
+	/// use arxiv::Archive;
+	///
+	/// assert_eq!(Archive::suggest("hepth"), vec![Archive::HepTh]);
+	/// assert_eq!(Archive::suggest("Q-BIO"), vec![Archive::QBio]);
+	/// assert!(Archive::suggest("biology").is_empty());
+	/// ```
+	#[must_use]
+	pub fn suggest(s: &str) -> Vec<Self> {
+		let max = max_distance(s);
+		let mut scored: Vec<_> = Self::ALL
+			.iter()
+			.map(|archive| (edit_distance(s, archive.as_str()), *archive))
+			.filter(|(distance, _)| *distance <= max)
+			.collect();
+		scored.sort();
+		scored.into_iter().map(|(_, archive)| archive).collect()
+	}
+
+	pub(crate) fn from_code(s: &str) -> Option<Self> {
+		Some(match s {
 			"astro-ph" => Self::AstroPh,
 			"cond-mat" => Self::CondMat,
 			"cs" => Self::Cs,
@@ -200,9 +570,56 @@ impl FromStr for Archive {
 			"q-fin" => Self::QFin,
 			"quant-ph" => Self::QuantPh,
 			"stat" => Self::Stat,
-			_ => return Err(()),
+			"acc-phys" => Self::AccPhys,
+			"adap-org" => Self::AdapOrg,
+			"alg-geom" => Self::AlgGeom,
+			"ao-sci" => Self::AoSci,
+			"atom-ph" => Self::AtomPh,
+			"bayes-an" => Self::BayesAn,
+			"chao-dyn" => Self::ChaoDyn,
+			"chem-ph" => Self::ChemPh,
+			"cmp-lg" => Self::CmpLg,
+			"comp-gas" => Self::CompGas,
+			"dg-ga" => Self::DgGa,
+			"funct-an" => Self::FunctAn,
+			"mtrl-th" => Self::MtrlTh,
+			"patt-sol" => Self::PattSol,
+			"plasm-ph" => Self::PlasmPh,
+			"q-alg" => Self::QAlg,
+			"solv-int" => Self::SolvInt,
+			"supr-con" => Self::SuprCon,
+			_ => return None,
 		})
 	}
+
+	/// Converts the article identifier to a URL where the abstract page is.
+	///
+	/// ```
+	/// use arxiv::Archive;
+	/// use url::Url;
+	///
+	/// let id = Archive::AstroPh;
+	/// let url = Url::from(id);
+	/// assert_eq!(url.to_string(), "https://arxiv.org/archive/astro-ph");
+	/// ```
+	#[cfg(feature = "url")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+	pub fn as_url(&self) -> url::Url {
+		url::Url::from(*self)
+	}
+}
+
+impl Display for Archive {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(self.as_str())
+	}
+}
+
+impl FromStr for Archive {
+	type Err = ArchiveError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::from_code(s).ok_or_else(|| ArchiveError::InvalidArchive(s.to_owned()))
+	}
 }
 
 #[cfg(feature = "url")]
@@ -215,19 +632,18 @@ impl From<Archive> for url::Url {
 
 #[cfg(test)]
 mod tests {
-	use crate::Archive;
+	use crate::{Archive, ArchiveError};
 	use std::str::FromStr;
 
 	#[test]
 	fn test_contains_subject() {
-		assert!(Archive::HepEx.contains_subjects());
-		assert!(Archive::HepLat.contains_subjects());
-		assert!(Archive::HepPh.contains_subjects());
-		assert!(Archive::HepTh.contains_subjects());
-		assert!(Archive::MathPh.contains_subjects());
-		assert!(Archive::NuclEx.contains_subjects());
-		assert!(Archive::NuclTh.contains_subjects());
-		assert!(Archive::QuantPh.contains_subjects());
+		assert!(Archive::AstroPh.contains_subjects());
+		assert!(Archive::Cs.contains_subjects());
+		assert!(Archive::Math.contains_subjects());
+		assert!(!Archive::GrQc.contains_subjects());
+		assert!(!Archive::HepTh.contains_subjects());
+		assert!(!Archive::QuantPh.contains_subjects());
+		assert!(!Archive::ChaoDyn.contains_subjects());
 	}
 
 	#[test]
@@ -235,6 +651,40 @@ mod tests {
 		let archive = Archive::from_str("astro-ph");
 		assert_eq!(archive, Ok(Archive::AstroPh));
 	}
+
+	#[test]
+	fn parse_archive_err() {
+		let archive = Archive::from_str("astroph");
+		assert_eq!(archive, Err(ArchiveError::InvalidArchive("astroph".to_owned())));
+	}
+
+	#[test]
+	fn display_roundtrip() {
+		for archive in Archive::ALL {
+			assert_eq!(Archive::from_str(&archive.to_string()), Ok(*archive));
+		}
+	}
+
+	#[test]
+	fn legacy_archives_are_retired() {
+		for archive in Archive::ALL {
+			assert_eq!(archive.is_legacy(), archive.active_period().is_retired());
+		}
+	}
+
+	#[test]
+	fn legacy_archive_has_active_successor() {
+		for archive in Archive::ALL.iter().filter(|archive| archive.is_legacy()) {
+			let successor = archive.subsumed_into().unwrap();
+			assert!(successor.valid_at(archive.active_period().end.unwrap()));
+		}
+	}
+
+	#[test]
+	fn suggest_ordered_by_distance() {
+		assert_eq!(Archive::suggest("nucl-xx"), vec![Archive::NuclEx, Archive::NuclTh]);
+		assert_eq!(Archive::suggest("hep_ph")[0], Archive::HepPh);
+	}
 }
 
 #[cfg(test)]
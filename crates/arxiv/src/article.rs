@@ -0,0 +1,437 @@
+use crate::{ARXIV_TIME_ZONE, ArticleCategories, ArticleId, CategoryId, Stamp};
+use jiff::Timestamp;
+use jiff::fmt::strtime::parse as jiff_parse;
+use jiff::tz::TimeZone;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An error that can occur when parsing an entry of the version history of an article
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArticleRevisionError {
+	/// Expected a version number in brackets, like `[v1]`
+	InvalidVersion,
+	/// Expected a submission time, like `Thu, 2 Jan 2020 18:00:00 UTC`
+	InvalidTimestamp,
+	/// Expected a size in kilobytes in parentheses, like `(512kb)`
+	InvalidSize,
+}
+
+impl Error for ArticleRevisionError {}
+
+impl Display for ArticleRevisionError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::InvalidVersion => f.write_str("Expected a version number, like \"[v1]\""),
+			Self::InvalidTimestamp => f.write_str("Invalid submission time"),
+			Self::InvalidSize => f.write_str("Expected a size in kilobytes, like \"(512kb)\""),
+		}
+	}
+}
+
+/// An entry of the version history of an article
+///
+/// # Examples
+/// ```
+/// use arxiv::ArticleRevision;
+///
+/// let revision = ArticleRevision::try_from("[v2] Thu, 2 Jan 2020 18:00:00 UTC (512 KB)").unwrap();
+/// assert_eq!(revision.version, 2);
+/// assert_eq!(revision.submitted.to_string(), "2020-01-02T18:00:00Z");
+/// assert_eq!(revision.size_kb, Some(512));
+/// assert_eq!(revision.to_string(), "[v2] Thu, 2 Jan 2020 18:00:00 UTC (512 KB)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArticleRevision {
+	/// The version number, starting at 1
+	pub version: u8,
+	/// The time at which the version was submitted
+	pub submitted: Timestamp,
+	/// The size of the submission in kilobytes, if known
+	pub size_kb: Option<u32>,
+}
+
+impl ArticleRevision {
+	/// Creates an entry of the version history.
+	#[must_use]
+	pub const fn new(version: u8, submitted: Timestamp, size_kb: Option<u32>) -> Self {
+		Self {
+			version,
+			submitted,
+			size_kb,
+		}
+	}
+}
+
+impl Display for ArticleRevision {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		let submitted = self.submitted.strftime("%a, %-d %b %Y %H:%M:%S UTC");
+		write!(f, "[v{}] {submitted}", self.version)?;
+		match self.size_kb {
+			Some(size) => write!(f, " ({size} KB)"),
+			None => Ok(()),
+		}
+	}
+}
+
+impl TryFrom<&str> for ArticleRevision {
+	type Error = ArticleRevisionError;
+
+	/// Parses an entry of the version history, as listed on the abstract page of an article (e.g.
+	/// `[v1] Mon, 2 Jan 2006 18:00:00 UTC (15kb)`), where the size is optional.
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		use ArticleRevisionError::*;
+
+		let (version, rest) = s
+			.trim()
+			.strip_prefix("[v")
+			.and_then(|s| s.split_once(']'))
+			.ok_or(InvalidVersion)?;
+		let version = version.parse().map_err(|_| InvalidVersion)?;
+
+		let (submitted, size_kb) = match rest.split_once('(') {
+			Some((submitted, size)) => (submitted, Some(parse_size(size).ok_or(InvalidSize)?)),
+			None => (rest, None),
+		};
+		let submitted = jiff_parse("%a, %d %b %Y %H:%M:%S UTC", submitted.trim())
+			.and_then(|tm| tm.to_datetime())
+			.and_then(|datetime| datetime.to_zoned(TimeZone::UTC))
+			.map_err(|_| InvalidTimestamp)?
+			.timestamp();
+
+		Ok(Self::new(version, submitted, size_kb))
+	}
+}
+
+/// Parses a size like `512kb)` or `1,234 KB)`, without its opening parenthesis.
+fn parse_size(s: &str) -> Option<u32> {
+	let size = s.trim().strip_suffix(')')?.trim_end();
+	let size = size
+		.strip_suffix("kb")
+		.or_else(|| size.strip_suffix("KB"))?
+		.trim_end();
+	if size.starts_with(',') || size.ends_with(',') {
+		return None;
+	}
+	size.replace(',', "").parse().ok()
+}
+
+/// An author of an article
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Author {
+	/// The name of the author, as written in the metadata
+	pub name: String,
+	/// The affiliations of the author, which are often unknown
+	pub affiliations: Vec<String>,
+}
+
+impl Author {
+	/// Creates an author without affiliations.
+	#[must_use]
+	pub fn new(name: impl Into<String>) -> Self {
+		Self {
+			name: name.into(),
+			affiliations: Vec::new(),
+		}
+	}
+}
+
+impl Display for Author {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.name)
+	}
+}
+
+/// An [`ArticleId`] that owns its number, so that an [`Article`] does not borrow from the text it
+/// was parsed from
+#[derive(Clone, PartialEq, Eq)]
+struct OwnedArticleId {
+	/// The identifier with an empty number, which is kept in `number` instead
+	id: ArticleId<'static>,
+	number: String,
+}
+
+impl OwnedArticleId {
+	fn new(id: ArticleId<'_>) -> Self {
+		Self {
+			id: id.with_number(""),
+			number: id.number().to_owned(),
+		}
+	}
+
+	fn get(&self) -> ArticleId<'_> {
+		self.id.with_number(&self.number)
+	}
+}
+
+impl Debug for OwnedArticleId {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		Debug::fmt(&self.get(), f)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for OwnedArticleId {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.get().serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for OwnedArticleId {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let s = String::deserialize(deserializer)?;
+		ArticleId::try_from(s.as_str())
+			.map(Self::new)
+			.map_err(serde::de::Error::custom)
+	}
+}
+
+/// The metadata of an article, as shared by the formats that arXiv provides it in
+///
+/// The identifier refers to the article as a whole, so it should not carry a version; the
+/// versions are listed in the version history instead. The article owns a copy of its identifier,
+/// so it can outlive the text it was parsed from.
+///
+/// # Examples
+/// ```
+/// use arxiv::{Article, ArticleCategories, ArticleId, ArticleRevision, Author};
+///
+/// let id = ArticleId::try_from("arXiv:2001.00001").unwrap();
+/// let categories = ArticleCategories::try_from("cs.LG stat.ML").unwrap();
+/// let mut article = Article::new(id, "A Title", categories);
+/// article.authors.push(Author::new("Jane Doe"));
+/// article.versions.push(ArticleRevision::try_from("[v1] Wed, 1 Jan 2020 18:00:00 UTC").unwrap());
+///
+/// assert_eq!(article.id(), id);
+/// assert_eq!(article.primary_category().to_string(), "cs.LG");
+/// assert_eq!(article.versioned_id(1).to_string(), "arXiv:2001.00001v1");
+///
+/// let stamp = article.stamp(1).unwrap();
+/// assert_eq!(stamp.to_string(), "arXiv:2001.00001v1 [cs.LG] 1 Jan 2020");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Article {
+	id: OwnedArticleId,
+	/// The title of the article
+	pub title: String,
+	/// The authors of the article, in the order that they are listed
+	pub authors: Vec<Author>,
+	/// The abstract of the article, which is called a summary since `abstract` is a keyword
+	pub summary: String,
+	/// The comments of the authors, such as the number of pages and figures
+	pub comments: Option<String>,
+	/// The reference to the published version of the article
+	pub journal_ref: Option<String>,
+	/// The DOI of the published version of the article (e.g. `10.1103/PhysRevD.76.013009`)
+	pub doi: Option<String>,
+	/// The report number that the authors' institution assigned to the article
+	pub report_no: Option<String>,
+	/// The URL of the license that the article is distributed under
+	pub license: Option<String>,
+	/// The primary category and cross-lists of the article
+	pub categories: ArticleCategories,
+	/// The version history, in chronological order
+	pub versions: Vec<ArticleRevision>,
+}
+
+impl Article {
+	/// Creates the metadata of an article with only the required fields, leaving the others empty.
+	#[must_use]
+	pub fn new(id: ArticleId<'_>, title: impl Into<String>, categories: ArticleCategories) -> Self {
+		Self {
+			id: OwnedArticleId::new(id),
+			title: title.into(),
+			authors: Vec::new(),
+			summary: String::new(),
+			comments: None,
+			journal_ref: None,
+			doi: None,
+			report_no: None,
+			license: None,
+			categories,
+			versions: Vec::new(),
+		}
+	}
+
+	/// The identifier of the article, without a version
+	#[must_use]
+	#[inline]
+	pub fn id(&self) -> ArticleId<'_> {
+		self.id.get()
+	}
+
+	/// Replaces the identifier of the article.
+	pub fn set_id(&mut self, id: ArticleId<'_>) {
+		self.id = OwnedArticleId::new(id);
+	}
+
+	/// The primary category of the article
+	#[must_use]
+	#[inline]
+	pub const fn primary_category(&self) -> CategoryId {
+		self.categories.primary()
+	}
+
+	/// The latest version in the version history
+	#[must_use]
+	pub fn latest_version(&self) -> Option<&ArticleRevision> {
+		self.versions.iter().max_by_key(|revision| revision.version)
+	}
+
+	/// An entry of the version history
+	#[must_use]
+	pub fn version(&self, version: u8) -> Option<&ArticleRevision> {
+		self.versions
+			.iter()
+			.find(|revision| revision.version == version)
+	}
+
+	/// The identifier of a version of the article
+	#[must_use]
+	pub fn versioned_id(&self, version: u8) -> ArticleId<'_> {
+		let mut id = self.id();
+		id.set_version(version);
+		id
+	}
+
+	/// The stamp on the PDF of a version of the article, which is dated in arXiv's time zone
+	///
+	/// Returns `None` if the version is not in the version history, or if the time zone database
+	/// is unavailable.
+	#[must_use]
+	pub fn stamp(&self, version: u8) -> Option<Stamp<'_>> {
+		let submitted = self
+			.version(version)?
+			.submitted
+			.in_tz(ARXIV_TIME_ZONE)
+			.ok()?;
+		Some(Stamp::new(self.versioned_id(version), self.primary_category(), submitted.date()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		Article, ArticleCategories, ArticleId, ArticleRevision, ArticleRevisionError, Author,
+	};
+
+	fn article() -> Article {
+		let id = ArticleId::try_from("arXiv:hep-th/9901001").unwrap();
+		let categories = ArticleCategories::try_from("hep-th gr-qc").unwrap();
+		let mut article = Article::new(id, "Title", categories);
+		article.authors = vec![Author::new("A. Author"), Author::new("B. Author")];
+		article.versions = vec![
+			ArticleRevision::try_from("[v1] Fri, 1 Jan 1999 03:00:00 UTC (15kb)").unwrap(),
+			ArticleRevision::try_from("[v2] Wed, 3 Feb 1999 12:00:00 UTC (16kb)").unwrap(),
+		];
+		article
+	}
+
+	#[test]
+	fn versions() {
+		let article = article();
+		assert_eq!(article.latest_version().map(|v| v.version), Some(2));
+		assert_eq!(article.version(3), None);
+		assert_eq!(article.versioned_id(2).to_string(), "arXiv:hep-th/9901001v2");
+	}
+
+	#[test]
+	fn stamps_are_dated_in_eastern_time() {
+		let article = article();
+		let stamp = article.stamp(1).unwrap();
+		assert_eq!(stamp.to_string(), "arXiv:hep-th/9901001v1 31 Dec 1998");
+		let stamp = article.stamp(2).unwrap();
+		assert_eq!(stamp.to_string(), "arXiv:hep-th/9901001v2 3 Feb 1999");
+		assert_eq!(article.stamp(3), None);
+	}
+
+	#[test]
+	fn revision_roundtrip() {
+		let s = "[v10] Mon, 12 Jun 2023 09:05:01 UTC (1024 KB)";
+		assert_eq!(ArticleRevision::try_from(s).unwrap().to_string(), s);
+
+		let revision = ArticleRevision::try_from("[v2] Mon, 12 Jun 2023 09:05:01 UTC (1,234 KB)");
+		assert_eq!(revision.unwrap().size_kb, Some(1234));
+
+		let revision = ArticleRevision::try_from("[v1] Mon, 12 Jun 2023 09:05:01 UTC").unwrap();
+		assert_eq!(revision.size_kb, None);
+		assert_eq!(revision.to_string(), "[v1] Mon, 12 Jun 2023 09:05:01 UTC");
+	}
+
+	#[test]
+	fn revision_errors() {
+		use ArticleRevisionError::*;
+
+		let parse = ArticleRevision::try_from;
+		assert_eq!(parse("v1 Mon, 12 Jun 2023 09:05:01 UTC"), Err(InvalidVersion));
+		assert_eq!(parse("[vX] Mon, 12 Jun 2023 09:05:01 UTC"), Err(InvalidVersion));
+		assert_eq!(parse("[v1] 12 Jun 2023"), Err(InvalidTimestamp));
+		assert_eq!(parse("[v1] Mon, 12 Jun 2023 09:05:01 UTC (big)"), Err(InvalidSize));
+		assert_eq!(parse("[v1] Mon, 12 Jun 2023 09:05:01 UTC (,234 KB)"), Err(InvalidSize));
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "json")]
+mod tests_json {
+	use crate::{Article, ArticleCategories, ArticleId, ArticleRevision, Author};
+
+	fn article() -> Article {
+		let id = ArticleId::try_from("arXiv:math.GT/0309136").unwrap();
+		let categories = ArticleCategories::try_from("math.GT math.AG").unwrap();
+		let mut article = Article::new(id, "Title", categories);
+		article.authors.push(Author::new("A. Author"));
+		article.doi = Some("10.1000/example".to_owned());
+		article
+			.versions
+			.push(ArticleRevision::try_from("[v1] Tue, 9 Sep 2003 18:00:00 UTC (15kb)").unwrap());
+		article
+	}
+
+	#[test]
+	fn roundtrip() {
+		let article = article();
+		let json = serde_json::to_string(&article).unwrap();
+		assert!(json.contains(r#""id":"arXiv:math.GT/0309136""#), "{json}");
+		assert!(json.contains(r#""categories":"math.GT math.AG""#), "{json}");
+		assert_eq!(serde_json::from_str::<Article>(&json).unwrap(), article);
+	}
+
+	#[test]
+	fn roundtrip_owned() {
+		let article = article();
+		let json = serde_json::to_vec(&article).unwrap();
+		assert_eq!(serde_json::from_reader::<_, Article>(json.as_slice()).unwrap(), article);
+
+		let value = serde_json::to_value(&article).unwrap();
+		assert_eq!(serde_json::from_value::<Article>(value).unwrap(), article);
+	}
+
+	#[test]
+	fn escaped_id() {
+		let json = serde_json::to_string(&article()).unwrap();
+		let json = json.replace("arXiv:math.GT", "arXiv\\u003amath.GT");
+		assert!(json.contains(r"arXiv\u003a"), "{json}");
+		assert_eq!(serde_json::from_str::<Article>(&json).unwrap(), article());
+	}
+
+	#[test]
+	fn invalid_fields() {
+		let json = r#"{"id":"arXiv:foo","title":"","authors":[],"summary":"","comments":null,
+			"journal_ref":null,"doi":null,"report_no":null,"license":null,
+			"categories":"cs.LG","versions":[]}"#;
+		assert!(serde_json::from_str::<Article>(json).is_err());
+
+		let json = json
+			.replace("arXiv:foo", "arXiv:2001.00001")
+			.replace("cs.LG", "cs.XX");
+		assert!(serde_json::from_str::<Article>(&json).is_err());
+	}
+}
@@ -0,0 +1,263 @@
+use crate::{CategoryId, CategoryIdError, CategorySet, Group};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// An error that can occur when parsing and validating the categories of an article
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArticleCategoriesError<'a> {
+	/// Expected at least one category, the primary one
+	ExpectedPrimary,
+	/// A category could not be parsed
+	InvalidCategory(CategoryIdError<'a>),
+	/// The category is listed more than once
+	DuplicateCategory(CategoryId),
+}
+
+impl Error for ArticleCategoriesError<'_> {}
+
+impl Display for ArticleCategoriesError<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::ExpectedPrimary => f.write_str("Expected to find a primary category"),
+			Self::InvalidCategory(e) => e.fmt(f),
+			Self::DuplicateCategory(c) => {
+				write!(f, "The category \"{c}\" is listed more than once")
+			}
+		}
+	}
+}
+
+impl<'a> From<CategoryIdError<'a>> for ArticleCategoriesError<'a> {
+	fn from(e: CategoryIdError<'a>) -> Self {
+		Self::InvalidCategory(e)
+	}
+}
+
+/// The categories of an article: exactly one primary category, followed by any number of
+/// cross-lists
+///
+/// The categories are kept in arXiv's canonical order, which lists the primary category first,
+/// followed by the cross-lists in the order that they were added.
+///
+/// # Examples
+/// ```
+/// use arxiv::{ArticleCategories, CategoryId, Group};
+///
+/// let categories = ArticleCategories::try_from("cs.LG stat.ML math.OC").unwrap();
+/// assert_eq!(categories.primary(), CategoryId::try_from("cs.LG").unwrap());
+/// assert_eq!(categories.cross_lists().len(), 2);
+/// assert_eq!(categories.group(), Group::Cs);
+/// assert_eq!(categories.to_string(), "cs.LG stat.ML math.OC");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArticleCategories {
+	primary: CategoryId,
+	cross_lists: Vec<CategoryId>,
+}
+
+impl ArticleCategories {
+	/// Creates the categories of an article without any cross-lists.
+	#[must_use]
+	pub const fn new(primary: CategoryId) -> Self {
+		Self {
+			primary,
+			cross_lists: Vec::new(),
+		}
+	}
+
+	/// Creates the categories of an article from its primary category and cross-lists, failing if
+	/// any category is listed more than once.
+	pub fn try_new<'a>(
+		primary: CategoryId,
+		cross_lists: impl IntoIterator<Item = CategoryId>,
+	) -> Result<Self, ArticleCategoriesError<'a>> {
+		let mut categories = Self::new(primary);
+		for category in cross_lists {
+			if !categories.add_cross_list(category) {
+				return Err(ArticleCategoriesError::DuplicateCategory(category));
+			}
+		}
+		Ok(categories)
+	}
+
+	/// The primary category of the article
+	#[must_use]
+	#[inline]
+	pub const fn primary(&self) -> CategoryId {
+		self.primary
+	}
+
+	/// The categories that the article is cross-listed in, in order
+	#[must_use]
+	#[inline]
+	pub fn cross_lists(&self) -> &[CategoryId] {
+		&self.cross_lists
+	}
+
+	/// The group of the primary category
+	#[must_use]
+	#[inline]
+	pub const fn group(&self) -> Group {
+		self.primary.group()
+	}
+
+	/// Cross-lists the article in another category.
+	///
+	/// Returns whether the category was added, which is not the case if the article is already
+	/// listed in it.
+	pub fn add_cross_list(&mut self, category: CategoryId) -> bool {
+		if self.contains(category) {
+			return false;
+		}
+		self.cross_lists.push(category);
+		true
+	}
+
+	/// Checks if the article is listed in the category, either as its primary or a cross-list.
+	#[must_use]
+	pub fn contains(&self, category: CategoryId) -> bool {
+		self.primary == category || self.cross_lists.contains(&category)
+	}
+
+	/// An iterator over all categories, starting with the primary category
+	pub fn iter(&self) -> impl Iterator<Item = CategoryId> + '_ {
+		std::iter::once(self.primary).chain(self.cross_lists.iter().copied())
+	}
+
+	/// The groups of all categories, starting with the group of the primary category
+	pub fn groups(&self) -> impl Iterator<Item = Group> + '_ {
+		let mut seen = Vec::new();
+		self.iter()
+			.map(|category| category.group())
+			.filter(move |group| {
+				let new = !seen.contains(group);
+				if new {
+					seen.push(*group);
+				}
+				new
+			})
+	}
+}
+
+impl From<&ArticleCategories> for CategorySet {
+	fn from(categories: &ArticleCategories) -> Self {
+		categories.iter().collect()
+	}
+}
+
+impl Display for ArticleCategories {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(f, "{}", self.primary)?;
+		for category in &self.cross_lists {
+			write!(f, " {category}")?;
+		}
+		Ok(())
+	}
+}
+
+/// Serializes the categories as arXiv's space-separated category field, like `cs.LG stat.ML`.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for ArticleCategories {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+/// Deserializes the categories from arXiv's space-separated category field.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for ArticleCategories {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let s = String::deserialize(deserializer)?;
+		Self::try_from(s.as_str()).map_err(serde::de::Error::custom)
+	}
+}
+
+impl<'a> TryFrom<&'a str> for ArticleCategories {
+	type Error = ArticleCategoriesError<'a>;
+
+	/// Parses arXiv's space-separated category field, like `cs.LG stat.ML`, whose first category
+	/// is the primary category.
+	fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+		let mut categories = s.split_ascii_whitespace().map(CategoryId::try_from);
+		let primary = categories
+			.next()
+			.ok_or(ArticleCategoriesError::ExpectedPrimary)??;
+		let cross_lists = categories.collect::<Result<Vec<_>, _>>()?;
+		Self::try_new(primary, cross_lists)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		ArticleCategories, ArticleCategoriesError, CategoryId, CategoryIdError, CategorySet, Group,
+	};
+
+	fn category(s: &str) -> CategoryId {
+		CategoryId::try_from(s).unwrap()
+	}
+
+	#[test]
+	fn parse_primary_only() {
+		let categories = ArticleCategories::try_from("hep-th").unwrap();
+		assert_eq!(categories, ArticleCategories::new(category("hep-th")));
+		assert_eq!(categories.iter().count(), 1);
+		assert!(categories.cross_lists().is_empty());
+	}
+
+	#[test]
+	fn parse_archive_with_subjects() {
+		let categories = ArticleCategories::try_from("astro-ph cond-mat").unwrap();
+		assert_eq!(categories.primary(), category("astro-ph"));
+		assert_eq!(categories.cross_lists(), [category("cond-mat")]);
+	}
+
+	#[test]
+	fn cross_lists_keep_order() {
+		let categories = ArticleCategories::try_from("  math.OC   cs.SY eess.SY cs.LG ").unwrap();
+		assert_eq!(
+			categories.cross_lists(),
+			[category("cs.SY"), category("eess.SY"), category("cs.LG")]
+		);
+		assert_eq!(categories.to_string(), "math.OC cs.SY eess.SY cs.LG");
+	}
+
+	#[test]
+	fn duplicates_are_rejected() {
+		let err = ArticleCategories::try_from("cs.LG stat.ML cs.LG");
+		assert_eq!(err, Err(ArticleCategoriesError::DuplicateCategory(category("cs.LG"))));
+
+		let err = ArticleCategories::try_from("cs.LG stat.ML stat.ML");
+		assert_eq!(err, Err(ArticleCategoriesError::DuplicateCategory(category("stat.ML"))));
+
+		let mut categories = ArticleCategories::new(category("cs.LG"));
+		assert!(!categories.add_cross_list(category("cs.LG")));
+		assert!(categories.add_cross_list(category("stat.ML")));
+	}
+
+	#[test]
+	fn parse_errors() {
+		assert_eq!(ArticleCategories::try_from(" "), Err(ArticleCategoriesError::ExpectedPrimary));
+		assert_eq!(
+			ArticleCategories::try_from("cs.LG foo.BAR"),
+			Err(ArticleCategoriesError::InvalidCategory(CategoryIdError::InvalidArchive("foo")))
+		);
+	}
+
+	#[test]
+	fn groups() {
+		let categories =
+			ArticleCategories::try_from("q-bio.NC cs.NE q-bio.QM physics.bio-ph").unwrap();
+		assert_eq!(categories.group(), Group::QBio);
+		let groups: Vec<_> = categories.groups().collect();
+		assert_eq!(groups, [Group::QBio, Group::Cs, Group::Physics]);
+	}
+
+	#[test]
+	fn into_set() {
+		let categories = ArticleCategories::try_from("stat.ML cs.LG").unwrap();
+		assert_eq!(CategorySet::from(&categories), CategorySet::try_from("cs.LG stat.ML").unwrap());
+	}
+}
@@ -1,6 +1,6 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-use crate::{ArticleVersion, parse_numbervv};
+use crate::{Archive, ArticleIdScheme, ArticleVersion, Subject, parse_numbervv};
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
@@ -21,6 +21,8 @@ pub enum ArticleIdError {
 	InvalidYear,
 	/// An invalid identifier outside of the inclusive [1, 99999] interval
 	InvalidId,
+	/// An old-scheme identifier with an unknown archive (or subject class)
+	InvalidArchive,
 }
 
 impl Error for ArticleIdError {}
@@ -37,6 +39,9 @@ impl Display for ArticleIdError {
 			Self::InvalidMonth => f.write_str("A valid month must be between 1 and 12."),
 			Self::InvalidYear => f.write_str("A valid year must be be between 2007 and 2099."),
 			Self::InvalidId => f.write_str("A valid identifier must be between 1 and 99999"),
+			Self::InvalidArchive => {
+				f.write_str("An old-scheme identifier must start with a valid archive.")
+			}
 		}
 	}
 }
@@ -53,6 +58,33 @@ impl Display for ArticleIdError {
 /// assert!(id.is_ok());
 /// ```
 ///
+/// Identifiers of the [old scheme][ArticleIdScheme::Old] (up to March 2007) also name the archive
+/// of the article:
+/// ```
+/// use arxiv::{Archive, ArticleId, ArticleIdScheme};
+///
+/// let id = ArticleId::try_from("arXiv:hep-th/9901001v2").unwrap();
+/// assert_eq!(id.scheme(), ArticleIdScheme::Old);
+/// assert_eq!(id.archive(), Some(Archive::HepTh));
+/// assert_eq!(id.year(), 1999);
+/// assert_eq!(id.number(), "001");
+/// ```
+///
+/// They may also name a subject class of the archive (as in `math.GT/0309136`), which is part of
+/// the identity of the identifier: it is printed back and used in URLs, and such an identifier
+/// compares (and hashes) unequal to the same identifier without it, even though both name the
+/// same article. Compare the [archive][ArticleId::archive()], year, month and number to match
+/// both forms:
+/// ```
+/// use arxiv::ArticleId;
+///
+/// let with_subject = ArticleId::try_from("arXiv:math.GT/0309136").unwrap();
+/// let without_subject = ArticleId::try_from("arXiv:math/0309136").unwrap();
+/// assert_ne!(with_subject, without_subject);
+/// assert_eq!(with_subject.archive(), without_subject.archive());
+/// assert_eq!(with_subject.number(), without_subject.number());
+/// ```
+///
 /// [arxiv-docs]: https://info.arxiv.org/help/arxiv_identifier.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ArticleId<'a> {
@@ -60,6 +92,8 @@ pub struct ArticleId<'a> {
 	month: i8,
 	number: &'a str,
 	version: ArticleVersion,
+	archive: Option<Archive>,
+	subject: Option<Subject>,
 }
 
 impl<'a> ArticleId<'a> {
@@ -67,10 +101,14 @@ impl<'a> ArticleId<'a> {
 	pub const MAX_YEAR: i16 = 2099i16;
 	pub const MIN_NUM_DIGITS: usize = 4usize;
 	pub const MAX_NUM_DIGITS: usize = 5usize;
+	pub const OLD_MIN_YEAR: i16 = 1991i16;
+	pub const OLD_MAX_YEAR: i16 = 2007i16;
+	pub const OLD_NUM_DIGITS: usize = 3usize;
 	pub(crate) const MIN_MONTH: i8 = 1i8;
 	pub(crate) const MAX_MONTH: i8 = 12i8;
 	pub(crate) const TOKEN_COLON: char = ':';
 	pub(crate) const TOKEN_DOT: char = '.';
+	pub(crate) const TOKEN_SLASH: char = '/';
 
 	/// This allows manually creating an [`ArticleId`] from the given components without any
 	/// validation. Only do this if you have already verified that the components are valid.
@@ -93,6 +131,8 @@ impl<'a> ArticleId<'a> {
 			month,
 			number,
 			version,
+			archive: None,
+			subject: None,
 		}
 	}
 
@@ -183,6 +223,151 @@ impl<'a> ArticleId<'a> {
 		Self::try_new(year, month, number, ArticleVersion::Latest)
 	}
 
+	/// This allows manually creating an old-scheme [`ArticleId`] from the given components without
+	/// any validation. Only do this if you have already verified that the components are valid:
+	///
+	///  - The year is between the inclusive range of [1991, 2007].
+	///  - The month is between the inclusive range of [1, 12].
+	///  - The unique number string only contains 3 ASCII digits.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Archive, ArticleId, ArticleVersion};
+	///
+	/// let id = ArticleId::new_old(Archive::HepTh, 1999, 1, "001", ArticleVersion::Num(2));
+	/// assert_eq!(id.to_string(), "arXiv:hep-th/9901001v2");
+	/// ```
+	#[inline]
+	pub const fn new_old(
+		archive: Archive,
+		year: i16,
+		month: i8,
+		number: &'a str,
+		version: ArticleVersion,
+	) -> Self {
+		Self {
+			year,
+			month,
+			number,
+			version,
+			archive: Some(archive),
+			subject: None,
+		}
+	}
+
+	/// This allows manually creating an old-scheme [`ArticleId`] that names the subject class of
+	/// its archive (such as `math.GT/0309136`) without any validation. The same requirements as
+	/// for [`ArticleId::new_old()`] apply.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{ArticleId, ArticleVersion, MathSubject, Subject};
+	///
+	/// let subject = Subject::Math(MathSubject::Gt);
+	/// let id = ArticleId::new_old_with_subject(subject, 2003, 9, "136", ArticleVersion::Num(1));
+	/// assert_eq!(id.to_string(), "arXiv:math.GT/0309136v1");
+	/// ```
+	#[inline]
+	pub const fn new_old_with_subject(
+		subject: Subject,
+		year: i16,
+		month: i8,
+		number: &'a str,
+		version: ArticleVersion,
+	) -> Self {
+		Self {
+			year,
+			month,
+			number,
+			version,
+			archive: Some(subject.archive()),
+			subject: Some(subject),
+		}
+	}
+
+	/// This allows manually creating an old-scheme [`ArticleId`] from the given components, and will
+	/// also validate each component for correctness. If any component is invalid, it will return
+	/// an [`ArticleIdError`].
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Archive, ArticleId, ArticleVersion};
+	///
+	/// let id = ArticleId::try_old(Archive::Math, 2003, 9, "136", ArticleVersion::Latest);
+	/// assert!(id.is_ok());
+	/// ```
+	pub fn try_old(
+		archive: Archive,
+		year: i16,
+		month: i8,
+		number: &'a str,
+		version: ArticleVersion,
+	) -> ArticleIdResult<'a> {
+		if !(Self::OLD_MIN_YEAR..=Self::OLD_MAX_YEAR).contains(&year) {
+			return Err(ArticleIdError::InvalidYear);
+		}
+
+		if !(Self::MIN_MONTH..=Self::MAX_MONTH).contains(&month) {
+			return Err(ArticleIdError::InvalidMonth);
+		}
+
+		let length_check = number.len() == Self::OLD_NUM_DIGITS;
+		let digit_check = number.chars().all(|c| c.is_ascii_digit());
+		if !length_check || !digit_check {
+			return Err(ArticleIdError::InvalidId);
+		}
+
+		Ok(Self::new_old(archive, year, month, number, version))
+	}
+
+	/// The identifier scheme that the identifier follows
+	#[must_use]
+	#[inline]
+	pub const fn scheme(&self) -> ArticleIdScheme {
+		match self.archive {
+			Some(_) => ArticleIdScheme::Old,
+			None => ArticleIdScheme::New,
+		}
+	}
+
+	/// The archive named by an old-scheme identifier
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Archive, ArticleId};
+	///
+	/// let id = ArticleId::try_from("arXiv:math.GT/0309136").unwrap();
+	/// assert_eq!(id.archive(), Some(Archive::Math));
+	///
+	/// let id = ArticleId::try_from("arXiv:2304.11188v1").unwrap();
+	/// assert_eq!(id.archive(), None);
+	/// ```
+	#[must_use]
+	#[inline]
+	pub const fn archive(&self) -> Option<Archive> {
+		self.archive
+	}
+
+	/// The subject class named by an old-scheme identifier, if any
+	///
+	/// The subject class is part of the identity of the identifier (see [`ArticleId`]).
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{ArticleId, MathSubject, Subject};
+	///
+	/// let id = ArticleId::try_from("arXiv:math.GT/0309136").unwrap();
+	/// assert_eq!(id.subject(), Some(Subject::Math(MathSubject::Gt)));
+	///
+	/// let id = ArticleId::try_from("arXiv:math/0309136").unwrap();
+	/// assert_eq!(id.subject(), None);
+	/// ```
+	#[must_use]
+	#[inline]
+	pub const fn subject(&self) -> Option<Subject> {
+		self.subject
+	}
+
 	/// Whether or not the identifier refers to the most recent version of the arXiv article
 	#[inline]
 	pub const fn is_latest(&self) -> bool {
@@ -283,6 +468,18 @@ impl<'a> ArticleId<'a> {
 		self.version = ArticleVersion::Latest;
 	}
 
+	/// The same identifier with another number, borrowed from wherever its owner keeps it
+	pub(crate) const fn with_number<'b>(&self, number: &'b str) -> ArticleId<'b> {
+		ArticleId {
+			year: self.year,
+			month: self.month,
+			number,
+			version: self.version,
+			archive: self.archive,
+			subject: self.subject,
+		}
+	}
+
 	/// Display the id as a unique identifier (after the arXiv literal)
 	///
 	/// ```
@@ -290,8 +487,22 @@ impl<'a> ArticleId<'a> {
 	///
 	/// let id = ArticleId::new_versioned(2020, 10, "14462", 2);
 	/// assert_eq!(id.as_unique_ident(), "2010.14462");
+	///
+	/// let id = ArticleId::try_from("arXiv:hep-th/9901001v2").unwrap();
+	/// assert_eq!(id.as_unique_ident(), "hep-th/9901001");
+	///
+	/// let id = ArticleId::try_from("arXiv:math.GT/0309136v1").unwrap();
+	/// assert_eq!(id.as_unique_ident(), "math.GT/0309136");
 	/// ```
 	pub fn as_unique_ident(&self) -> String {
+		if let Some(archive) = self.archive {
+			let (year, month, number) = (self.year % 100, self.month, self.number);
+			return match self.subject {
+				Some(subject) => format!("{archive}.{subject}/{year:02}{month:02}{number}"),
+				None => format!("{archive}/{year:02}{month:02}{number}"),
+			};
+		}
+
 		let mut year_str = self.year.to_string();
 		let (_, half_year) = year_str.as_mut_str().split_at(2);
 
@@ -336,6 +547,10 @@ impl<'a> TryFrom<&'a str> for ArticleId<'a> {
 			return Err(ExpectedBeginningLiteral);
 		}
 
+		if let Some((archive, ident)) = parts[1].split_once(ArticleId::TOKEN_SLASH) {
+			return Self::try_from_old(archive, ident);
+		}
+
 		let inner_parts: Vec<&str> = parts[1].split(ArticleId::TOKEN_DOT).collect();
 		if inner_parts.len() != 2 {
 			return Err(ExpectedNumberVv);
@@ -345,14 +560,61 @@ impl<'a> TryFrom<&'a str> for ArticleId<'a> {
 		let numbervv = inner_parts[1];
 
 		// validate and compose the final Arxiv struct
-		let year = date[0..2].parse::<i16>().map_err(|_| InvalidYear)?;
-		let month = date[2..4].parse::<i8>().map_err(|_| InvalidMonth)?;
+		let year = date
+			.get(0..2)
+			.and_then(|s| s.parse::<i16>().ok())
+			.ok_or(InvalidYear)?;
+		let month = date
+			.get(2..4)
+			.and_then(|s| s.parse::<i8>().ok())
+			.ok_or(InvalidMonth)?;
 		let (number, version) = parse_numbervv(numbervv).ok_or(ExpectedNumberVv)?;
 
 		Self::try_new(year + 2000i16, month, number, version)
 	}
 }
 
+impl<'a> ArticleId<'a> {
+	/// Parses the components of an old-scheme identifier, such as `hep-th` and `9901001v2`.
+	///
+	/// The archive may be followed by a subject class (e.g. `math.GT`), which is kept as part of
+	/// the identifier.
+	fn try_from_old(archive: &str, ident: &'a str) -> ArticleIdResult<'a> {
+		use ArticleIdError::*;
+
+		let (archive, subject) = match archive.split_once(Self::TOKEN_DOT) {
+			Some((archive, subject)) => (archive, Some(subject)),
+			None => (archive, None),
+		};
+		let archive = Archive::from_code(archive).ok_or(InvalidArchive)?;
+		let subject = match subject {
+			Some(subject) => Some(Subject::from_code(archive, subject).ok_or(InvalidArchive)?),
+			None => None,
+		};
+
+		let digits = Self::OLD_NUM_DIGITS + 4;
+		let date_number = ident
+			.get(..digits)
+			.filter(|s| s.bytes().all(|c| c.is_ascii_digit()))
+			.ok_or(ExpectedNumberVv)?;
+		let year = date_number[0..2].parse::<i16>().map_err(|_| InvalidYear)?;
+		let month = date_number[2..4].parse::<i8>().map_err(|_| InvalidMonth)?;
+		let version = match &ident[digits..] {
+			"" => ArticleVersion::Latest,
+			v => v
+				.strip_prefix('v')
+				.and_then(|v| v.parse::<u8>().ok())
+				.map(ArticleVersion::Num)
+				.ok_or(ExpectedNumberVv)?,
+		};
+
+		let century = if year >= 91 { 1900i16 } else { 2000i16 };
+		let mut id = Self::try_old(archive, century + year, month, &date_number[4..], version)?;
+		id.subject = subject;
+		Ok(id)
+	}
+}
+
 #[cfg(feature = "url")]
 #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
 impl<'a> From<ArticleId<'a>> for url::Url {
@@ -362,6 +624,26 @@ impl<'a> From<ArticleId<'a>> for url::Url {
 	}
 }
 
+/// Serializes the identifier as a string, like `arXiv:2001.00001v1`.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for ArticleId<'_> {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+/// Deserializes the identifier from a string that it borrows its number from, so the string must
+/// not need unescaping. An [`Article`](crate::Article) owns its identifier instead.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de: 'a, 'a> serde::Deserialize<'de> for ArticleId<'a> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let s = <&'de str>::deserialize(deserializer)?;
+		Self::try_from(s).map_err(serde::de::Error::custom)
+	}
+}
+
 #[cfg(test)]
 mod test_display {
 	use crate::ArticleId;
@@ -381,7 +663,7 @@ mod test_display {
 
 #[cfg(test)]
 mod tests_parse_ok {
-	use crate::{ArticleId, ArticleVersion};
+	use crate::{Archive, ArticleId, ArticleVersion, MathSubject, Subject};
 
 	#[test]
 	fn from_readme() {
@@ -413,6 +695,31 @@ mod tests_parse_ok {
 		assert_eq!(id2.to_string(), String::from("arXiv:1412.7878"));
 	}
 
+	#[test]
+	fn old_scheme() {
+		let id = ArticleId::try_from("arXiv:hep-th/9901001v2");
+		assert_eq!(
+			id,
+			Ok(ArticleId::new_old(Archive::HepTh, 1999, 1, "001", ArticleVersion::Num(2)))
+		);
+
+		let id = ArticleId::try_from("arXiv:math/0703999").unwrap();
+		assert_eq!(id.year(), 2007);
+		assert_eq!(id.to_string(), "arXiv:math/0703999");
+	}
+
+	#[test]
+	fn old_scheme_subject_class() {
+		let id = ArticleId::try_from("arXiv:math.GT/0309136v1").unwrap();
+		let subject = Subject::Math(MathSubject::Gt);
+		assert_eq!(
+			id,
+			ArticleId::new_old_with_subject(subject, 2003, 9, "136", ArticleVersion::Num(1))
+		);
+		assert_eq!(id.archive(), Some(Archive::Math));
+		assert_eq!(id.to_string(), "arXiv:math.GT/0309136v1");
+	}
+
 	#[test]
 	fn with_number_5_digits() {
 		let id1 = ArticleId::new_latest(2014, 1, "00008");
@@ -439,6 +746,16 @@ mod tests_parse_err {
 		assert_eq!(id, Err(ArticleIdError::ExpectedNumberVv));
 	}
 
+	#[test]
+	fn short_or_non_ascii_date() {
+		let err = |s| ArticleId::try_from(s).unwrap_err();
+		assert_eq!(err("arXiv:."), ArticleIdError::InvalidYear);
+		assert_eq!(err("arXiv:1.0001"), ArticleIdError::InvalidYear);
+		assert_eq!(err("arXiv:1é.0001"), ArticleIdError::InvalidYear);
+		assert_eq!(err("arXiv:070.0001"), ArticleIdError::InvalidMonth);
+		assert_eq!(err("arXiv:070é.0001"), ArticleIdError::InvalidMonth);
+	}
+
 	#[test]
 	fn invalid_year() {
 		let maybe_id = ArticleId::try_latest(2006, 1, "00001");
@@ -457,15 +774,29 @@ mod tests_parse_err {
 
 		assert_eq!(maybe_id, Err(ArticleIdError::InvalidId));
 	}
+
+	#[test]
+	fn old_scheme_invalid() {
+		let err = |s| ArticleId::try_from(s).unwrap_err();
+		assert_eq!(err("arXiv:foo-bar/9901001"), ArticleIdError::InvalidArchive);
+		assert_eq!(err("arXiv:math.XX/0309136"), ArticleIdError::InvalidArchive);
+		assert_eq!(err("arXiv:hep-th/990100"), ArticleIdError::ExpectedNumberVv);
+		assert_eq!(err("arXiv:hep-th/9901001x"), ArticleIdError::ExpectedNumberVv);
+		assert_eq!(err("arXiv:hep-th/990100é"), ArticleIdError::ExpectedNumberVv);
+		assert_eq!(err("arXiv:hep-th/99010é1"), ArticleIdError::ExpectedNumberVv);
+		assert_eq!(err("arXiv:hep-th/9013001"), ArticleIdError::InvalidYear);
+		assert_eq!(err("arXiv:hep-th/9913001"), ArticleIdError::InvalidMonth);
+	}
 }
 
 #[cfg(test)]
 #[cfg(feature = "url")]
 mod tests_url {
-	use crate::{ArticleId, ArticleVersion};
+	use crate::{Archive, ArticleId, ArticleVersion};
 	use url::Url;
 
 	#[test]
+	#[allow(clippy::zero_prefixed_literal)]
 	fn url_from_id() {
 		let id = ArticleId::try_new(2007, 01, "00001", ArticleVersion::Latest).unwrap();
 		let url = Url::from(id);
@@ -475,4 +806,13 @@ mod tests_url {
 		assert_eq!(url.path(), "/abs/0701.00001");
 		assert_eq!(url.to_string(), "https://arxiv.org/abs/0701.00001");
 	}
+
+	#[test]
+	fn url_from_old_id() {
+		let id = ArticleId::new_old(Archive::HepTh, 1999, 1, "001", ArticleVersion::Num(2));
+		let url = Url::from(id);
+
+		assert_eq!(url.path(), "/abs/hep-th/9901001v2");
+		assert_eq!(url.to_string(), "https://arxiv.org/abs/hep-th/9901001v2");
+	}
 }
@@ -1,12 +1,16 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-use crate::{Archive, Group};
+use crate::suggest::{edit_distance, max_distance};
+use crate::{
+	ActivePeriod, Archive, ArticleId, CategorySet, CondMatSubject, CsSubject, EconSubject,
+	EessSubject, Group, MathSubject, PhysicsSubject, QFinSubject, StatSubject, Subject,
+};
+use jiff::civil::{Date, date};
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use std::str::FromStr;
 
 /// [`Result`] type alias holding either a [`CategoryId`] or [`CategoryIdError`]
-pub type CategoryIdResult<'a> = Result<CategoryId<'a>, CategoryIdError<'a>>;
+pub type CategoryIdResult<'a> = Result<CategoryId, CategoryIdError<'a>>;
 
 /// An error that can occur when parsing and validating arXiv category identifiers
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,7 +29,7 @@ impl Display for CategoryIdError<'_> {
 			Self::InvalidArchive(s) => write!(f, "Invalid arXiv archive identifier: {s}"),
 			Self::InvalidArchiveSubject(archive, subject_str) => write!(
 				f,
-				"The arXiv subject \"{archive}\" does not fall under the archive \"{subject_str}\""
+				"The arXiv subject \"{subject_str}\" does not fall under the archive \"{archive}\""
 			),
 		}
 	}
@@ -33,33 +37,16 @@ impl Display for CategoryIdError<'_> {
 
 /// An identifier for arXiv categories, which are composed of an archive and category
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct CategoryId<'a> {
+pub struct CategoryId {
 	group: Group,
 	archive: Archive,
-	subject: &'a str,
+	subject: Option<Subject>,
 }
 
-impl<'a> CategoryId<'a> {
+impl CategoryId {
 	pub(crate) const TOKEN_DELIM: char = '.';
-	pub(crate) const COMPSCI_TABLE: &'static [&'static str] = &[
-		"AI", "AR", "CC", "CE", "CG", "CL", "CR", "CV", "CY", "DB", "DC", "DL", "DM", "DS", "ET",
-		"FL", "GL", "GR", "GT", "HC", "IR", "IT", "LG", "LO", "MA", "MM", "MS", "NA", "NI", "OH",
-		"OS", "PF", "PL", "RO", "SC", "SD", "SE", "SI", "SY",
-	];
-
-	pub(crate) const MATH_TABLE: &'static [&'static str] = &[
-		"AC", "AG", "AP", "AT", "CA", "CO", "CT", "CV", "DG", "DS", "FA", "GM", "GN", "GR", "GT",
-		"HO", "IT", "KT", "LO", "MG", "MP", "NA", "NT", "OA", "OC", "PR", "QA", "RA", "RT", "SG",
-		"SP", "ST",
-	];
-
-	pub(crate) const PHYSICS_TABLE: &'static [&'static str] = &[
-		"acc-ph", "ao-ph", "app-ph", "atm-clus", "atom-ph", "bio-ph", "chem-ph", "class-ph",
-		"comp-ph", "data-an", "ed-pn", "flu-dyn", "gen-ph", "geo-ph", "hist-ph", "ins-det",
-		"med-ph", "optics", "plasm-ph", "pop-ph", "soc-ph", "space-ph",
-	];
-
-	pub(super) const fn new(group: Group, archive: Archive, subject: &'a str) -> Self {
+
+	pub(super) const fn new(group: Group, archive: Archive, subject: Option<Subject>) -> Self {
 		Self {
 			group,
 			archive,
@@ -69,57 +56,53 @@ impl<'a> CategoryId<'a> {
 
 	/// Checks if the string is a valid group identifier, based on the archive and category.
 	///
+	/// An empty subject string refers to the archive itself, which is not a category of archives
+	/// that were divided into subject classes from the start (see [`CategoryId::from_archive()`]).
+	///
 	/// Valid archive identifiers are listed under the official website's page for [category taxonomy][arxiv-cat].
 	///
 	/// [arxiv-cat]: <https://arxiv.org/category_taxonomy>
-	pub fn try_new(archive: Archive, subject: &'a str) -> Option<Self> {
-		let is_valid = match archive {
-			Archive::AstroPh => matches!(subject, "CO" | "EP" | "GA" | "HE" | "IM" | "SR"),
-			Archive::CondMat => matches!(subject, |"dis-nn"| "mes-hall"
-				| "mtrl-sci" | "other"
-				| "quant-gas"
-				| "soft" | "stat-mech"
-				| "str-el" | "supr-con"),
-			Archive::Cs => Self::COMPSCI_TABLE.binary_search(&subject).is_ok(),
-			Archive::Econ => matches!(subject, "EM" | "GN" | "TH"),
-			Archive::Eess => matches!(subject, "AS" | "IV" | "SP" | "SY"),
-			Archive::GrQc => subject.is_empty(),
-			Archive::HepEx => subject.is_empty(),
-			Archive::HepLat => subject.is_empty(),
-			Archive::HepPh => subject.is_empty(),
-			Archive::HepTh => subject.is_empty(),
-			Archive::MathPh => subject.is_empty(),
-			Archive::Math => Self::MATH_TABLE.binary_search(&subject).is_ok(),
-			Archive::Nlin => matches!(subject, "AO" | "CD" | "CG" | "PS" | "SI"),
-			Archive::NuclEx => subject.is_empty(),
-			Archive::NuclTh => subject.is_empty(),
-			Archive::Physics => Self::PHYSICS_TABLE.binary_search(&subject).is_ok(),
-			Archive::QBio => matches!(
-				subject,
-				"BM" | "CB" | "GN" | "MN" | "NC" | "OT" | "PE" | "QM" | "SC" | "TO"
-			),
-			Archive::QFin => {
-				matches!(subject, "CP" | "EC" | "GN" | "MF" | "PM" | "PR" | "RM" | "ST" | "SR")
-			}
-			Archive::QuantPh => subject.is_empty(),
-			Archive::Stat => matches!(subject, "AP" | "CO" | "ME" | "ML" | "OT" | "TH"),
-		};
+	pub fn try_new(archive: Archive, subject: &str) -> Option<Self> {
+		match subject.is_empty() {
+			true => Self::from_archive(archive),
+			false => Subject::from_code(archive, subject).map(Self::from),
+		}
+	}
 
-		is_valid.then(|| Self::new(Group::from(archive), archive, subject))
+	/// The category of the archive itself, without a subject class.
+	///
+	/// This is the only category of archives that are not divided into subject classes (such as
+	/// `hep-th`). A few archives were divided into subject classes later, and their articles were
+	/// filed under the archive itself until then (such as `astro-ph` until 2009). Returns [`None`]
+	/// for the archives that were divided into subject classes from the start (such as `cs`).
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Archive, CategoryId};
+	///
+	/// let category = CategoryId::from_archive(Archive::AstroPh).unwrap();
+	/// assert_eq!(category.subject(), None);
+	/// assert_eq!(category, CategoryId::try_from("astro-ph").unwrap());
+	///
+	/// assert_eq!(CategoryId::from_archive(Archive::Cs), None);
+	/// ```
+	#[must_use]
+	pub fn from_archive(archive: Archive) -> Option<Self> {
+		has_archive_category(archive).then(|| Self::new(Group::from(archive), archive, None))
 	}
 
 	/// Parse a bracketed string like `[astro-ph.CE]`
 	///
 	/// # Examples
 	/// ```
-	/// use arxiv::{Archive, CategoryId, Group};
+	/// use arxiv::{Archive, AstroPhSubject, CategoryId, Group, Subject};
 	///
-	/// let category = CategoryId::try_from("astro-ph.EP").unwrap();
+	/// let category = CategoryId::parse_bracketed("[astro-ph.EP]").unwrap();
 	/// assert_eq!(category.group(), Group::Physics);
 	/// assert_eq!(category.archive(), Archive::AstroPh);
-	/// assert_eq!(category.subject(), "EP");
+	/// assert_eq!(category.subject(), Some(Subject::AstroPh(AstroPhSubject::Ep)));
 	/// ```
-	pub fn parse_bracketed(s: &'a str) -> Option<Self> {
+	pub fn parse_bracketed(s: &str) -> Option<Self> {
 		match s.starts_with('[') && s.ends_with(']') {
 			true => Self::try_from(&s[1..s.len() - 1]).ok(),
 			false => None,
@@ -141,49 +124,336 @@ impl<'a> CategoryId<'a> {
 		self.archive
 	}
 
-	/// The subject class of the arXiv category
+	/// The subject class of the arXiv category, if it is not the category of the archive itself
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{CategoryId, CsSubject, Subject};
+	///
+	/// let category = CategoryId::try_from("cs.LG").unwrap();
+	/// match category.subject() {
+	///     Some(Subject::Cs(CsSubject::Lg)) => {}
+	///     _ => unreachable!(),
+	/// }
+	///
+	/// let category = CategoryId::try_from("hep-th").unwrap();
+	/// assert_eq!(category.subject(), None);
+	/// ```
 	#[must_use]
 	#[inline]
-	pub fn subject(&self) -> &'a str {
+	pub const fn subject(&self) -> Option<Subject> {
 		self.subject
 	}
+
+	/// The period during which the category accepted new submissions.
+	///
+	/// The category of an archive itself accepted submissions until the archive was divided into
+	/// subject classes, such as `astro-ph` whose subject classes were introduced in 2009. From then
+	/// on, each subject class accepted submissions unless it was introduced even later.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::CategoryId;
+	/// use jiff::civil::date;
+	///
+	/// let category = CategoryId::try_from("astro-ph.HE").unwrap();
+	/// assert_eq!(category.active_period().start, date(2009, 1, 1));
+	///
+	/// let category = CategoryId::try_from("astro-ph").unwrap();
+	/// assert_eq!(category.active_period().start, date(1992, 4, 1));
+	/// assert_eq!(category.active_period().end, Some(date(2009, 1, 1)));
+	/// ```
+	#[must_use]
+	pub fn active_period(&self) -> ActivePeriod {
+		let archive_period = self.archive.active_period();
+		if !self.archive.contains_subjects() {
+			return archive_period;
+		}
+
+		let subjects_start = subjects_introduced(self.archive);
+		let start = match self.subject {
+			None => return ActivePeriod::new(archive_period.start, Some(subjects_start)),
+			Some(Subject::CondMat(CondMatSubject::QuantGas)) => date(2008, 10, 1),
+			Some(Subject::Eess(EessSubject::Sy)) => date(2019, 5, 1),
+			Some(Subject::Physics(PhysicsSubject::AppPh)) => date(2016, 10, 1),
+			Some(Subject::QFin(QFinSubject::Ec | QFinSubject::Gn)) => date(2014, 2, 1),
+			Some(_) => subjects_start,
+		};
+		ActivePeriod::new(start.max(subjects_start), archive_period.end)
+	}
+
+	/// Checks if the category accepted new submissions on the given date.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::CategoryId;
+	/// use jiff::civil::date;
+	///
+	/// let category = CategoryId::try_from("econ.EM").unwrap();
+	/// assert!(category.valid_at(date(2020, 1, 1)));
+	/// assert!(!category.valid_at(date(2010, 1, 1)));
+	/// ```
+	#[must_use]
+	pub fn valid_at(&self, date: Date) -> bool {
+		self.active_period().contains(date)
+	}
+
+	/// Checks if the category accepted new submissions during the month of the identifier.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{ArticleId, CategoryId};
+	///
+	/// let category = CategoryId::try_from("eess.SP").unwrap();
+	/// assert!(category.valid_for(&ArticleId::try_from("arXiv:1709.00001").unwrap()));
+	/// assert!(!category.valid_for(&ArticleId::try_from("arXiv:1708.00001").unwrap()));
+	/// ```
+	#[must_use]
+	pub fn valid_for(&self, id: &ArticleId) -> bool {
+		self.active_period().contains_month(id.year(), id.month())
+	}
+
+	/// The equivalent category listed under another archive, if any.
+	///
+	/// arXiv treats a few categories as aliases of each other, such as `cs.SY` and `eess.SY`,
+	/// where an article in either category is listed in both.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::CategoryId;
+	///
+	/// let category = CategoryId::try_from("math.MP").unwrap();
+	/// assert_eq!(category.alias(), CategoryId::try_from("math-ph").ok());
+	/// assert_eq!(CategoryId::try_from("cs.LG").unwrap().alias(), None);
+	/// ```
+	#[must_use]
+	pub fn alias(&self) -> Option<Self> {
+		let subject: Subject = match self.subject {
+			Some(Subject::Cs(CsSubject::It)) => MathSubject::It.into(),
+			Some(Subject::Cs(CsSubject::Na)) => MathSubject::Na.into(),
+			Some(Subject::Cs(CsSubject::Sy)) => EessSubject::Sy.into(),
+			Some(Subject::Econ(EconSubject::Gn)) => QFinSubject::Ec.into(),
+			Some(Subject::Eess(EessSubject::Sy)) => CsSubject::Sy.into(),
+			Some(Subject::Math(MathSubject::It)) => CsSubject::It.into(),
+			Some(Subject::Math(MathSubject::Mp)) => {
+				return Self::from_archive(Archive::MathPh);
+			}
+			Some(Subject::Math(MathSubject::Na)) => CsSubject::Na.into(),
+			Some(Subject::Math(MathSubject::St)) => StatSubject::Th.into(),
+			Some(Subject::QFin(QFinSubject::Ec)) => EconSubject::Gn.into(),
+			Some(Subject::Stat(StatSubject::Th)) => MathSubject::St.into(),
+			None if self.archive == Archive::MathPh => MathSubject::Mp.into(),
+			_ => return None,
+		};
+		Some(Self::from(subject))
+	}
+
+	/// Suggests categories the user may have meant when the given string is not a valid category,
+	/// in canonical order.
+	///
+	/// A misspelled archive or subject class is matched by its edit distance to the taxonomy, while
+	/// a subject class under the wrong archive is matched to the archives that do contain it. Any
+	/// category sharing the name of a suggestion (such as `cs.LG` and `stat.ML`, which are both
+	/// "Machine Learning") is suggested as well. Letter case is ignored, and valid categories have
+	/// no suggestions.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::CategoryId;
+	///
+	/// let to_strings = |v: Vec<CategoryId>| v.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+	/// assert_eq!(to_strings(CategoryId::suggest("cs.ML")), ["cs.LG", "stat.ML"]);
+	/// assert_eq!(to_strings(CategoryId::suggest("astroph.HE")), ["astro-ph.HE"]);
+	/// assert_eq!(to_strings(CategoryId::suggest("hepth")), ["hep-th"]);
+	/// assert!(CategoryId::suggest("cs.LG").is_empty());
+	/// ```
+	#[must_use]
+	pub fn suggest(s: &str) -> Vec<Self> {
+		if Self::try_from(s).is_ok() {
+			return Vec::new();
+		}
+
+		let (archive_str, subject_str) = s.split_once(Self::TOKEN_DELIM).unwrap_or((s, ""));
+		let archives = match Archive::from_code(archive_str) {
+			Some(archive) => vec![archive],
+			None => Archive::suggest(archive_str),
+		};
+
+		let mut suggestions = CategorySet::new();
+		for archive in &archives {
+			match subject_str.is_empty() {
+				true => suggestions.extend(Self::from_archive(*archive)),
+				false => suggestions.extend(
+					archive
+						.subjects()
+						.iter()
+						.filter(|subject| {
+							edit_distance(subject_str, subject.as_str())
+								<= max_distance(subject_str)
+						})
+						.map(|subject| Self::from(*subject)),
+				),
+			}
+		}
+
+		// a valid subject class filed under the wrong archive
+		if !subject_str.is_empty() {
+			suggestions.extend(
+				Archive::ALL
+					.iter()
+					.flat_map(|archive| archive.subjects())
+					.filter(|subject| subject.as_str().eq_ignore_ascii_case(subject_str))
+					.map(|subject| Self::from(*subject)),
+			);
+		}
+
+		// categories that are listed under more than one archive, like cs.LG and stat.ML
+		let aliases: CategorySet = CategorySet::all()
+			.iter()
+			.filter(|candidate| {
+				suggestions.iter().any(|suggestion| {
+					matches!(
+						(candidate.subject(), suggestion.subject()),
+						(Some(a), Some(b)) if a.name() == b.name()
+					)
+				})
+			})
+			.collect();
+
+		(suggestions | aliases).iter().collect()
+	}
+
+	/// Parses a category while tolerating common variations in its spelling, which are normalized to
+	/// the canonical category.
+	///
+	/// Unlike [`CategoryId::try_from()`], which only accepts categories exactly as arXiv writes them,
+	/// this ignores letter case, accepts underscores in place of hyphens, and ignores surrounding
+	/// whitespace.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::CategoryId;
+	///
+	/// let category = CategoryId::parse_lenient("Cs.Lg").unwrap();
+	/// assert_eq!(category.to_string(), "cs.LG");
+	///
+	/// let category = CategoryId::parse_lenient("cond_mat.str_el").unwrap();
+	/// assert_eq!(category.to_string(), "cond-mat.str-el");
+	/// ```
+	pub fn parse_lenient(s: &str) -> CategoryIdResult<'_> {
+		use CategoryIdError::*;
+
+		let (archive_str, subject) = Self::split(s.trim())?;
+		let archive = Archive::ALL
+			.iter()
+			.find(|archive| is_lenient_match(archive.as_str(), archive_str))
+			.copied()
+			.ok_or(InvalidArchive(archive_str))?;
+
+		match subject {
+			Some(subject) => archive
+				.subjects()
+				.iter()
+				.find(|candidate| is_lenient_match(candidate.as_str(), subject))
+				.map(|candidate| Self::from(*candidate))
+				.ok_or(InvalidArchiveSubject(archive, subject)),
+			None => Self::from_archive(archive).ok_or(ExpectedSubject),
+		}
+	}
+
+	/// Splits a category into its archive and, if present, its subject class.
+	fn split(s: &str) -> Result<(&str, Option<&str>), CategoryIdError<'_>> {
+		match s.split_once(Self::TOKEN_DELIM) {
+			Some((_, "")) => Err(CategoryIdError::ExpectedSubject),
+			Some((archive_str, subject)) => Ok((archive_str, Some(subject))),
+			None => Ok((s, None)),
+		}
+	}
+}
+
+/// The first day on which articles of an archive were filed under its subject classes, which is
+/// the start of the archive unless it was divided into subject classes later
+const fn subjects_introduced(archive: Archive) -> Date {
+	match archive {
+		Archive::AstroPh => date(2009, 1, 1),
+		Archive::CondMat | Archive::Physics => date(1996, 10, 1),
+		Archive::Math => date(1998, 1, 1),
+		archive => archive.active_period().start,
+	}
+}
+
+/// Checks if the archive is a category of its own, which is the case unless it was divided into
+/// subject classes from the start
+pub(crate) const fn has_archive_category(archive: Archive) -> bool {
+	!archive.contains_subjects()
+		|| matches!(archive, Archive::AstroPh | Archive::CondMat | Archive::Math | Archive::Physics)
+}
+
+/// Compares a canonical identifier against a string, ignoring letter case and treating
+/// underscores as hyphens.
+fn is_lenient_match(canonical: &str, s: &str) -> bool {
+	canonical.len() == s.len()
+		&& canonical.bytes().zip(s.bytes()).all(|(a, b)| match b {
+			b'_' => a == b'-',
+			b => a.eq_ignore_ascii_case(&b),
+		})
 }
 
-impl Display for CategoryId<'_> {
+impl From<Subject> for CategoryId {
+	fn from(subject: Subject) -> Self {
+		let archive = subject.archive();
+		Self::new(Group::from(archive), archive, Some(subject))
+	}
+}
+
+impl Display for CategoryId {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-		write!(f, "{}.{}", self.archive, self.subject)
+		match self.subject {
+			Some(subject) => write!(f, "{}.{}", self.archive, subject),
+			None => write!(f, "{}", self.archive),
+		}
 	}
 }
 
-impl<'a> TryFrom<&'a str> for CategoryId<'a> {
+impl<'a> TryFrom<&'a str> for CategoryId {
 	type Error = CategoryIdError<'a>;
 	fn try_from(s: &'a str) -> Result<Self, Self::Error> {
 		use CategoryIdError::*;
 
-		let parts: Vec<&str> = s.split(Self::TOKEN_DELIM).collect();
-		if parts.len() != 2 {
-			return Err(ExpectedSubject);
-		}
-
-		let (archive_str, subject) = (parts[0], parts[1]);
-		if subject.is_empty() {
-			return Err(ExpectedSubject);
+		let (archive_str, subject) = Self::split(s)?;
+		let archive = Archive::from_code(archive_str).ok_or(InvalidArchive(archive_str))?;
+		match subject {
+			Some(subject) => {
+				Self::try_new(archive, subject).ok_or(InvalidArchiveSubject(archive, subject))
+			}
+			None => Self::from_archive(archive).ok_or(ExpectedSubject),
 		}
-
-		let archive = Archive::from_str(archive_str).map_err(|_| InvalidArchive(archive_str))?;
-		Self::try_new(archive, subject).ok_or(InvalidArchiveSubject(archive, subject))
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::{Archive, CategoryId, CategoryIdError, Group};
+	use crate::{Archive, CategoryId, CategoryIdError, CategorySet, CsSubject, Group, Subject};
 	use CategoryIdError::*;
+	use jiff::civil::date;
 
 	#[test]
 	fn parse_ok() {
 		let cat_id = CategoryId::try_from("cs.LG");
-		assert_eq!(cat_id, Ok(CategoryId::new(Group::Cs, Archive::Cs, "LG")));
+		let subject = Some(Subject::Cs(CsSubject::Lg));
+		assert_eq!(cat_id, Ok(CategoryId::new(Group::Cs, Archive::Cs, subject)));
+	}
+
+	#[test]
+	fn parse_ok_without_subject() {
+		let cat_id = CategoryId::try_from("quant-ph");
+		assert_eq!(cat_id, Ok(CategoryId::new(Group::Physics, Archive::QuantPh, None)));
+	}
+
+	#[test]
+	fn parse_err_unexpected_subject() {
+		let cat_id = CategoryId::try_from("hep-th.LG");
+		assert_eq!(cat_id, Err(InvalidArchiveSubject(Archive::HepTh, "LG")));
 	}
 
 	#[test]
@@ -192,6 +462,13 @@ mod tests {
 		assert_eq!(cat_id, Err(ExpectedSubject));
 	}
 
+	#[test]
+	fn parse_ok_archive_with_subjects() {
+		let cat_id = CategoryId::try_from("astro-ph");
+		assert_eq!(cat_id, Ok(CategoryId::new(Group::Physics, Archive::AstroPh, None)));
+		assert_eq!(CategoryId::try_from("cond-mat").unwrap().to_string(), "cond-mat");
+	}
+
 	#[test]
 	fn parse_err_expected_subject_empty() {
 		let cat_id = CategoryId::try_from("cs.");
@@ -215,4 +492,125 @@ mod tests {
 		let cat_id = CategoryId::try_new(Archive::AstroPh, "HE").unwrap();
 		assert_eq!(cat_id.to_string(), "astro-ph.HE");
 	}
+
+	#[test]
+	fn display_category_without_subject() {
+		let cat_id = CategoryId::try_new(Archive::GrQc, "").unwrap();
+		assert_eq!(cat_id.to_string(), "gr-qc");
+	}
+
+	#[test]
+	fn parse_lenient_ok() {
+		let expected = CategoryId::try_from("astro-ph.HE");
+		assert_eq!(CategoryId::parse_lenient("astro-ph.he"), expected);
+		assert_eq!(CategoryId::parse_lenient("ASTRO_PH.HE"), expected);
+		assert_eq!(CategoryId::parse_lenient(" astro-ph.HE\n"), expected);
+		assert_eq!(CategoryId::parse_lenient("CS.lg"), CategoryId::try_from("cs.LG"));
+		assert_eq!(CategoryId::parse_lenient("Hep_Th"), CategoryId::try_from("hep-th"));
+	}
+
+	#[test]
+	fn parse_lenient_err() {
+		assert_eq!(
+			CategoryId::parse_lenient("cs.l_g"),
+			Err(InvalidArchiveSubject(Archive::Cs, "l_g"))
+		);
+		assert_eq!(CategoryId::parse_lenient("ecot.LG"), Err(InvalidArchive("ecot")));
+		assert_eq!(CategoryId::parse_lenient("Math."), Err(ExpectedSubject));
+		assert_eq!(
+			CategoryId::parse_lenient("Math"),
+			Ok(CategoryId::from_archive(Archive::Math).unwrap())
+		);
+	}
+
+	#[test]
+	fn parse_strict_is_case_sensitive() {
+		assert_eq!(CategoryId::try_from("cs.lg"), Err(InvalidArchiveSubject(Archive::Cs, "lg")));
+		assert_eq!(CategoryId::try_from("CS.LG"), Err(InvalidArchive("CS")));
+	}
+
+	#[test]
+	fn suggest_misspelled_subject() {
+		let suggestions = CategoryId::suggest("cond-mat.str-l");
+		assert_eq!(suggestions, vec![CategoryId::try_from("cond-mat.str-el").unwrap()]);
+	}
+
+	#[test]
+	fn suggest_ignores_case() {
+		let suggestions = CategoryId::suggest("CS.ai");
+		assert_eq!(suggestions, vec![CategoryId::try_from("cs.AI").unwrap()]);
+	}
+
+	#[test]
+	fn suggest_nothing() {
+		assert!(CategoryId::suggest("foo.bar").is_empty());
+		assert!(CategoryId::suggest("").is_empty());
+	}
+
+	#[test]
+	fn active_period_of_subject() {
+		let category = CategoryId::try_from("astro-ph.CO").unwrap();
+		assert!(category.valid_at(date(2009, 1, 1)));
+		assert!(!category.valid_at(date(2008, 12, 31)));
+	}
+
+	#[test]
+	fn active_period_of_archive_with_subjects() {
+		let category = CategoryId::try_from("cond-mat").unwrap();
+		assert!(category.valid_at(date(1992, 4, 1)));
+		assert!(!category.valid_at(date(1996, 10, 1)));
+		assert!(
+			CategoryId::try_from("cond-mat.supr-con")
+				.unwrap()
+				.valid_at(date(1996, 10, 1))
+		);
+	}
+
+	#[test]
+	fn archives_divided_from_the_start() {
+		for archive in Archive::ALL {
+			let category = CategoryId::from_archive(*archive);
+			match archive {
+				Archive::Cs
+				| Archive::Econ
+				| Archive::Eess
+				| Archive::Nlin
+				| Archive::QBio
+				| Archive::QFin
+				| Archive::Stat => assert_eq!(category, None, "{archive}"),
+				_ => assert!(category.is_some(), "{archive}"),
+			}
+		}
+		assert_eq!(CategoryId::try_new(Archive::Econ, ""), None);
+		assert_eq!(CategoryId::parse_lenient("Q-Fin"), Err(ExpectedSubject));
+	}
+
+	#[test]
+	fn active_period_of_legacy_archive() {
+		let category = CategoryId::try_from("solv-int").unwrap();
+		assert!(category.valid_at(date(1999, 12, 31)));
+		assert!(!category.valid_at(date(2000, 1, 1)));
+		assert!(
+			CategoryId::try_from("nlin.SI")
+				.unwrap()
+				.valid_at(date(2000, 1, 1))
+		);
+	}
+
+	#[test]
+	fn aliases_are_symmetric() {
+		for category in CategorySet::all() {
+			if let Some(alias) = category.alias() {
+				assert_ne!(alias.archive(), category.archive());
+				assert_eq!(alias.alias(), Some(category));
+			}
+		}
+	}
+
+	#[test]
+	fn from_subject() {
+		let cat_id = CategoryId::from(CsSubject::Ne);
+		assert_eq!(cat_id.archive(), Archive::Cs);
+		assert_eq!(cat_id.to_string(), "cs.NE");
+	}
 }
@@ -0,0 +1,504 @@
+use crate::{Archive, CategoryId, CategoryIdError, Group, has_archive_category};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
+
+/// Every archive, ordered by group and then by archive, matching the ordering of [`CategoryId`]
+const ARCHIVES: [Archive; Archive::ALL.len()] = [
+	Archive::Cs,
+	Archive::CmpLg,
+	Archive::Econ,
+	Archive::Eess,
+	Archive::Math,
+	Archive::AlgGeom,
+	Archive::DgGa,
+	Archive::FunctAn,
+	Archive::QAlg,
+	Archive::AstroPh,
+	Archive::CondMat,
+	Archive::GrQc,
+	Archive::HepEx,
+	Archive::HepLat,
+	Archive::HepPh,
+	Archive::HepTh,
+	Archive::MathPh,
+	Archive::Nlin,
+	Archive::NuclEx,
+	Archive::NuclTh,
+	Archive::Physics,
+	Archive::QuantPh,
+	Archive::AccPhys,
+	Archive::AdapOrg,
+	Archive::AoSci,
+	Archive::AtomPh,
+	Archive::BayesAn,
+	Archive::ChaoDyn,
+	Archive::ChemPh,
+	Archive::CompGas,
+	Archive::MtrlTh,
+	Archive::PattSol,
+	Archive::PlasmPh,
+	Archive::SolvInt,
+	Archive::SuprCon,
+	Archive::QBio,
+	Archive::QFin,
+	Archive::Stat,
+];
+
+/// The position of each archive (indexed by its discriminant) within [`ARCHIVES`]
+const RANKS: [usize; Archive::ALL.len()] = {
+	let mut ranks = [0; Archive::ALL.len()];
+	let mut i = 0;
+	while i < ARCHIVES.len() {
+		ranks[ARCHIVES[i] as usize] = i;
+		i += 1;
+	}
+	ranks
+};
+
+/// The bit offset of each archive within a [`CategorySet`], indexed by rank, where the category of
+/// the archive itself (if it is one) comes before its subject classes
+const OFFSETS: [usize; Archive::ALL.len() + 1] = {
+	let mut offsets = [0; Archive::ALL.len() + 1];
+	let mut i = 0;
+	while i < ARCHIVES.len() {
+		let own = has_archive_category(ARCHIVES[i]) as usize;
+		offsets[i + 1] = offsets[i] + own + ARCHIVES[i].subjects().len();
+		i += 1;
+	}
+	offsets
+};
+
+/// The number of known categories in the taxonomy
+const LEN: usize = OFFSETS[Archive::ALL.len()];
+
+const WORDS: usize = LEN.div_ceil(u64::BITS as usize);
+
+const fn index_of(category: &CategoryId) -> usize {
+	let archive = category.archive();
+	let offset = OFFSETS[RANKS[archive as usize]];
+	match category.subject() {
+		Some(subject) => offset + has_archive_category(archive) as usize + subject.position(),
+		None => offset,
+	}
+}
+
+fn category_at(index: usize) -> Option<CategoryId> {
+	let rank = OFFSETS
+		.partition_point(|offset| *offset <= index)
+		.checked_sub(1)?;
+	let archive = *ARCHIVES.get(rank)?;
+	let position = index - OFFSETS[rank];
+	match has_archive_category(archive) {
+		true if position == 0 => CategoryId::from_archive(archive),
+		true => Some(CategoryId::from(archive.subjects()[position - 1])),
+		false => Some(CategoryId::from(archive.subjects()[position])),
+	}
+}
+
+/// A compact set of arXiv categories, such as the primary and cross-listed categories of a paper
+///
+/// The set is a fixed-size bitset over every known category, so membership checks and set
+/// operations never allocate. Iterating over the set yields categories in canonical order, which
+/// is the ordering of [`CategoryId`] (by group, then archive, then subject class).
+///
+/// # Examples
+/// ```
+/// use arxiv::{Archive, CategoryId, CategorySet, Group};
+///
+/// let set = CategorySet::try_from("cs.LG stat.ML math.OC").unwrap();
+/// assert_eq!(set.len(), 3);
+/// assert!(set.contains(CategoryId::try_from("stat.ML").unwrap()));
+/// assert!(set.contains_group(Group::Math));
+/// assert!(!set.contains_group(Group::Physics));
+/// assert!(set.contains_archive(Archive::Cs));
+/// assert_eq!(set.to_string(), "cs.LG math.OC stat.ML");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CategorySet {
+	words: [u64; WORDS],
+}
+
+impl CategorySet {
+	/// The number of known categories, which is the maximum length of a set
+	pub const CAPACITY: usize = LEN;
+
+	/// Creates an empty set.
+	#[must_use]
+	#[inline]
+	pub const fn new() -> Self {
+		Self { words: [0; WORDS] }
+	}
+
+	/// Creates a set holding every known category.
+	#[must_use]
+	pub fn all() -> Self {
+		Self::from_range(0, LEN)
+	}
+
+	/// Creates a set holding every category of the archive, including the archive itself if it is a
+	/// category of its own.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Archive, CategorySet};
+	///
+	/// assert_eq!(CategorySet::from_archive(Archive::Econ).len(), 3);
+	/// assert_eq!(CategorySet::from_archive(Archive::HepTh).len(), 1);
+	/// ```
+	#[must_use]
+	pub fn from_archive(archive: Archive) -> Self {
+		let rank = RANKS[archive as usize];
+		Self::from_range(OFFSETS[rank], OFFSETS[rank + 1])
+	}
+
+	/// Creates a set holding every category of every archive in the group.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{CategorySet, Group};
+	///
+	/// let physics = CategorySet::from_group(Group::Physics);
+	/// assert!(physics.contains("hep-th".try_into().unwrap()));
+	/// assert!(physics.contains("astro-ph.HE".try_into().unwrap()));
+	/// ```
+	#[must_use]
+	pub fn from_group(group: Group) -> Self {
+		group
+			.archives()
+			.iter()
+			.fold(Self::new(), |set, archive| set | Self::from_archive(*archive))
+	}
+
+	fn from_range(start: usize, end: usize) -> Self {
+		let mut set = Self::new();
+		(start..end).for_each(|index| set.set_bit(index));
+		set
+	}
+
+	/// Parses a space-separated list of categories with [`CategoryId::parse_lenient()`].
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::CategorySet;
+	///
+	/// let set = CategorySet::parse_lenient("CS.lg  stat.ml\tcond_mat.str_el").unwrap();
+	/// assert_eq!(set.to_string(), "cs.LG cond-mat.str-el stat.ML");
+	/// ```
+	pub fn parse_lenient(s: &str) -> Result<Self, CategoryIdError<'_>> {
+		s.split_whitespace()
+			.map(CategoryId::parse_lenient)
+			.collect()
+	}
+
+	/// Adds a category to the set, returning whether it was newly inserted.
+	pub fn insert(&mut self, category: CategoryId) -> bool {
+		let index = index_of(&category);
+		let inserted = !self.has_bit(index);
+		self.set_bit(index);
+		inserted
+	}
+
+	/// Removes a category from the set, returning whether it was present.
+	pub fn remove(&mut self, category: CategoryId) -> bool {
+		let index = index_of(&category);
+		let removed = self.has_bit(index);
+		self.words[index / 64] &= !(1 << (index % 64));
+		removed
+	}
+
+	/// Removes every category from the set.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.words = [0; WORDS];
+	}
+
+	/// Checks if the set contains the category.
+	#[must_use]
+	#[inline]
+	pub fn contains(&self, category: CategoryId) -> bool {
+		self.has_bit(index_of(&category))
+	}
+
+	/// Checks if the set contains any category of the archive.
+	#[must_use]
+	pub fn contains_archive(&self, archive: Archive) -> bool {
+		!self.is_disjoint(&Self::from_archive(archive))
+	}
+
+	/// Checks if the set contains any category of the group.
+	#[must_use]
+	pub fn contains_group(&self, group: Group) -> bool {
+		!self.is_disjoint(&Self::from_group(group))
+	}
+
+	/// The number of categories in the set
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.words
+			.iter()
+			.map(|word| word.count_ones() as usize)
+			.sum()
+	}
+
+	/// Checks if the set contains no categories.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.words.iter().all(|word| *word == 0)
+	}
+
+	/// The categories that are in either set
+	#[must_use]
+	pub fn union(&self, other: &Self) -> Self {
+		*self | *other
+	}
+
+	/// The categories that are in both sets
+	#[must_use]
+	pub fn intersection(&self, other: &Self) -> Self {
+		*self & *other
+	}
+
+	/// The categories that are in this set, but not in the other set
+	#[must_use]
+	pub fn difference(&self, other: &Self) -> Self {
+		*self - *other
+	}
+
+	/// Checks if every category of this set is also in the other set.
+	#[must_use]
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.intersection(other) == *self
+	}
+
+	/// Checks if the sets have no categories in common.
+	#[must_use]
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.intersection(other).is_empty()
+	}
+
+	/// Iterates over the categories of the set in canonical order.
+	#[inline]
+	pub fn iter(&self) -> CategorySetIter {
+		CategorySetIter {
+			set: *self,
+			index: 0,
+		}
+	}
+
+	#[inline]
+	fn has_bit(&self, index: usize) -> bool {
+		self.words[index / 64] & (1 << (index % 64)) != 0
+	}
+
+	#[inline]
+	fn set_bit(&mut self, index: usize) {
+		self.words[index / 64] |= 1 << (index % 64);
+	}
+
+	fn zip_with(self, other: Self, f: impl Fn(u64, u64) -> u64) -> Self {
+		let mut words = [0; WORDS];
+		for (i, word) in words.iter_mut().enumerate() {
+			*word = f(self.words[i], other.words[i]);
+		}
+		Self { words }
+	}
+}
+
+impl BitOr for CategorySet {
+	type Output = Self;
+	fn bitor(self, rhs: Self) -> Self::Output {
+		self.zip_with(rhs, |a, b| a | b)
+	}
+}
+
+impl BitOrAssign for CategorySet {
+	fn bitor_assign(&mut self, rhs: Self) {
+		*self = *self | rhs;
+	}
+}
+
+impl BitAnd for CategorySet {
+	type Output = Self;
+	fn bitand(self, rhs: Self) -> Self::Output {
+		self.zip_with(rhs, |a, b| a & b)
+	}
+}
+
+impl BitAndAssign for CategorySet {
+	fn bitand_assign(&mut self, rhs: Self) {
+		*self = *self & rhs;
+	}
+}
+
+impl Sub for CategorySet {
+	type Output = Self;
+	fn sub(self, rhs: Self) -> Self::Output {
+		self.zip_with(rhs, |a, b| a & !b)
+	}
+}
+
+impl SubAssign for CategorySet {
+	fn sub_assign(&mut self, rhs: Self) {
+		*self = *self - rhs;
+	}
+}
+
+impl From<CategoryId> for CategorySet {
+	fn from(category: CategoryId) -> Self {
+		let mut set = Self::new();
+		set.insert(category);
+		set
+	}
+}
+
+impl FromIterator<CategoryId> for CategorySet {
+	fn from_iter<T: IntoIterator<Item = CategoryId>>(iter: T) -> Self {
+		let mut set = Self::new();
+		set.extend(iter);
+		set
+	}
+}
+
+impl Extend<CategoryId> for CategorySet {
+	fn extend<T: IntoIterator<Item = CategoryId>>(&mut self, iter: T) {
+		iter.into_iter().for_each(|category| {
+			self.insert(category);
+		});
+	}
+}
+
+impl IntoIterator for CategorySet {
+	type Item = CategoryId;
+	type IntoIter = CategorySetIter;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl IntoIterator for &CategorySet {
+	type Item = CategoryId;
+	type IntoIter = CategorySetIter;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl Debug for CategorySet {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl Display for CategorySet {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		for (i, category) in self.iter().enumerate() {
+			if i > 0 {
+				f.write_str(" ")?;
+			}
+			write!(f, "{category}")?;
+		}
+		Ok(())
+	}
+}
+
+impl<'a> TryFrom<&'a str> for CategorySet {
+	type Error = CategoryIdError<'a>;
+
+	/// Parses a space-separated list of categories, like `cs.LG stat.ML math.OC`.
+	fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+		s.split_ascii_whitespace()
+			.map(CategoryId::try_from)
+			.collect()
+	}
+}
+
+/// An iterator over the categories of a [`CategorySet`] in canonical order
+#[derive(Debug, Clone)]
+pub struct CategorySetIter {
+	set: CategorySet,
+	index: usize,
+}
+
+impl Iterator for CategorySetIter {
+	type Item = CategoryId;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.index < LEN {
+			let index = self.index;
+			self.index += 1;
+			if self.set.has_bit(index) {
+				return category_at(index);
+			}
+		}
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ARCHIVES, LEN, category_at, index_of};
+	use crate::{Archive, CategoryId, CategorySet, Group};
+
+	#[test]
+	fn archives_are_exhaustive() {
+		let mut archives = ARCHIVES.to_vec();
+		archives.sort();
+		assert_eq!(archives, Archive::ALL);
+	}
+
+	#[test]
+	fn index_roundtrip() {
+		for index in 0..LEN {
+			let category = category_at(index).unwrap();
+			assert_eq!(index_of(&category), index);
+		}
+		assert_eq!(category_at(LEN), None);
+	}
+
+	#[test]
+	fn canonical_order() {
+		let categories: Vec<_> = CategorySet::all().iter().collect();
+		assert_eq!(categories.len(), CategorySet::CAPACITY);
+		assert!(categories.windows(2).all(|pair| pair[0] < pair[1]));
+	}
+
+	#[test]
+	fn insert_remove() {
+		let category = CategoryId::try_from("cs.LG").unwrap();
+		let mut set = CategorySet::new();
+
+		assert!(set.insert(category));
+		assert!(!set.insert(category));
+		assert!(set.contains(category));
+		assert!(set.remove(category));
+		assert!(!set.remove(category));
+		assert!(set.is_empty());
+	}
+
+	#[test]
+	fn set_operations() {
+		let a = CategorySet::try_from("cs.LG stat.ML").unwrap();
+		let b = CategorySet::try_from("stat.ML math.OC").unwrap();
+
+		assert_eq!(a.union(&b).to_string(), "cs.LG math.OC stat.ML");
+		assert_eq!(a.intersection(&b).to_string(), "stat.ML");
+		assert_eq!(a.difference(&b).to_string(), "cs.LG");
+		assert!(a.intersection(&b).is_subset(&a));
+		assert!(!a.is_disjoint(&b));
+	}
+
+	#[test]
+	fn group_and_archive_queries() {
+		let set = CategorySet::try_from("hep-th gr-qc").unwrap();
+		assert!(set.contains_group(Group::Physics));
+		assert!(set.contains_archive(Archive::HepTh));
+		assert!(!set.contains_archive(Archive::HepPh));
+		assert!(!set.contains_group(Group::Cs));
+	}
+
+	#[test]
+	fn parse_err() {
+		let set = CategorySet::try_from("cs.LG foo.BAR");
+		assert!(set.is_err());
+	}
+}
@@ -0,0 +1,268 @@
+use crate::{Archive, CategoryId, CategorySet};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A set of categories that share endorsements
+///
+/// Authors need to be [endorsed][endorsement] before their first submission to a category; an
+/// endorsement for one category covers every category in its endorsement domain. Legacy archives
+/// belong to the domain of the archive they were subsumed into.
+///
+/// # Examples
+/// ```
+/// use arxiv::{CategoryId, EndorsementDomain};
+///
+/// let category = CategoryId::try_from("hep-th").unwrap();
+/// assert_eq!(category.endorsement_domain(), EndorsementDomain::Hep);
+///
+/// let peers = category.endorsement_peers();
+/// assert!(peers.contains(CategoryId::try_from("hep-ph").unwrap()));
+/// assert!(!peers.contains(CategoryId::try_from("gr-qc").unwrap()));
+/// ```
+///
+/// [endorsement]: <https://info.arxiv.org/help/endorsement.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EndorsementDomain {
+	/// Astrophysics (`astro-ph`)
+	AstroPh,
+	/// Condensed Matter (`cond-mat`)
+	CondMat,
+	/// Computer Science (`cs`)
+	Cs,
+	/// Economics (`econ`)
+	Econ,
+	/// Electrical Engineering and Systems Science (`eess`)
+	Eess,
+	/// General Relativity and Quantum Cosmology (`gr-qc`)
+	GrQc,
+	/// High Energy Physics (`hep-ex`, `hep-lat`, `hep-ph` and `hep-th`)
+	Hep,
+	/// Mathematics (`math` and `math-ph`)
+	Math,
+	/// Nonlinear Sciences (`nlin`)
+	Nlin,
+	/// Nuclear Physics (`nucl-ex` and `nucl-th`)
+	Nucl,
+	/// Physics (`physics`)
+	Physics,
+	/// Quantitative Biology (`q-bio`)
+	QBio,
+	/// Quantitative Finance (`q-fin`)
+	QFin,
+	/// Quantum Physics (`quant-ph`)
+	QuantPh,
+	/// Statistics (`stat`)
+	Stat,
+}
+
+impl EndorsementDomain {
+	/// All endorsement domains, in canonical order
+	pub const ALL: &'static [Self] = &[
+		Self::AstroPh,
+		Self::CondMat,
+		Self::Cs,
+		Self::Econ,
+		Self::Eess,
+		Self::GrQc,
+		Self::Hep,
+		Self::Math,
+		Self::Nlin,
+		Self::Nucl,
+		Self::Physics,
+		Self::QBio,
+		Self::QFin,
+		Self::QuantPh,
+		Self::Stat,
+	];
+
+	/// The identifier of the domain, as used by arXiv (e.g. `hep`)
+	#[must_use]
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::AstroPh => "astro-ph",
+			Self::CondMat => "cond-mat",
+			Self::Cs => "cs",
+			Self::Econ => "econ",
+			Self::Eess => "eess",
+			Self::GrQc => "gr-qc",
+			Self::Hep => "hep",
+			Self::Math => "math",
+			Self::Nlin => "nlin",
+			Self::Nucl => "nucl",
+			Self::Physics => "physics",
+			Self::QBio => "q-bio",
+			Self::QFin => "q-fin",
+			Self::QuantPh => "quant-ph",
+			Self::Stat => "stat",
+		}
+	}
+
+	/// The current archives that belong to the domain
+	///
+	/// ```
+	/// use arxiv::{Archive, EndorsementDomain};
+	///
+	/// assert_eq!(EndorsementDomain::Nucl.archives(), &[Archive::NuclEx, Archive::NuclTh]);
+	/// ```
+	#[must_use]
+	pub const fn archives(&self) -> &'static [Archive] {
+		match self {
+			Self::AstroPh => &[Archive::AstroPh],
+			Self::CondMat => &[Archive::CondMat],
+			Self::Cs => &[Archive::Cs],
+			Self::Econ => &[Archive::Econ],
+			Self::Eess => &[Archive::Eess],
+			Self::GrQc => &[Archive::GrQc],
+			Self::Hep => &[
+				Archive::HepEx,
+				Archive::HepLat,
+				Archive::HepPh,
+				Archive::HepTh,
+			],
+			Self::Math => &[Archive::Math, Archive::MathPh],
+			Self::Nlin => &[Archive::Nlin],
+			Self::Nucl => &[Archive::NuclEx, Archive::NuclTh],
+			Self::Physics => &[Archive::Physics],
+			Self::QBio => &[Archive::QBio],
+			Self::QFin => &[Archive::QFin],
+			Self::QuantPh => &[Archive::QuantPh],
+			Self::Stat => &[Archive::Stat],
+		}
+	}
+
+	/// The current categories that an endorsement for the domain covers
+	///
+	/// ```
+	/// use arxiv::{CategoryId, EndorsementDomain};
+	///
+	/// let categories = EndorsementDomain::Cs.categories();
+	/// assert!(categories.contains(CategoryId::try_from("cs.LG").unwrap()));
+	/// assert!(!categories.contains(CategoryId::try_from("stat.ML").unwrap()));
+	/// ```
+	#[must_use]
+	pub fn categories(&self) -> CategorySet {
+		self.archives()
+			.iter()
+			.map(|archive| CategorySet::from_archive(*archive))
+			.fold(CategorySet::new(), |set, archive| set | archive)
+	}
+
+	/// Checks if an endorsement for the domain covers the category.
+	#[must_use]
+	pub fn contains(&self, category: CategoryId) -> bool {
+		category.endorsement_domain() == *self
+	}
+}
+
+impl Display for EndorsementDomain {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(self.as_str())
+	}
+}
+
+impl Archive {
+	/// The endorsement domain of the archive
+	///
+	/// ```
+	/// use arxiv::{Archive, EndorsementDomain};
+	///
+	/// assert_eq!(Archive::NuclTh.endorsement_domain(), EndorsementDomain::Nucl);
+	/// assert_eq!(Archive::ChaoDyn.endorsement_domain(), EndorsementDomain::Nlin);
+	/// ```
+	#[must_use]
+	pub const fn endorsement_domain(&self) -> EndorsementDomain {
+		match self {
+			Self::AstroPh => EndorsementDomain::AstroPh,
+			Self::CondMat | Self::MtrlTh | Self::SuprCon => EndorsementDomain::CondMat,
+			Self::Cs | Self::CmpLg => EndorsementDomain::Cs,
+			Self::Econ => EndorsementDomain::Econ,
+			Self::Eess => EndorsementDomain::Eess,
+			Self::GrQc => EndorsementDomain::GrQc,
+			Self::HepEx | Self::HepLat | Self::HepPh | Self::HepTh => EndorsementDomain::Hep,
+			Self::Math | Self::MathPh | Self::AlgGeom | Self::DgGa | Self::FunctAn | Self::QAlg => {
+				EndorsementDomain::Math
+			}
+			Self::Nlin
+			| Self::AdapOrg
+			| Self::ChaoDyn
+			| Self::CompGas
+			| Self::PattSol
+			| Self::SolvInt => EndorsementDomain::Nlin,
+			Self::NuclEx | Self::NuclTh => EndorsementDomain::Nucl,
+			Self::Physics
+			| Self::AccPhys
+			| Self::AoSci
+			| Self::AtomPh
+			| Self::BayesAn
+			| Self::ChemPh
+			| Self::PlasmPh => EndorsementDomain::Physics,
+			Self::QBio => EndorsementDomain::QBio,
+			Self::QFin => EndorsementDomain::QFin,
+			Self::QuantPh => EndorsementDomain::QuantPh,
+			Self::Stat => EndorsementDomain::Stat,
+		}
+	}
+}
+
+impl CategoryId {
+	/// The endorsement domain of the category
+	#[must_use]
+	pub const fn endorsement_domain(&self) -> EndorsementDomain {
+		self.archive().endorsement_domain()
+	}
+
+	/// The current categories that share the endorsement domain of the category, including the
+	/// category itself (unless it belongs to a legacy archive)
+	#[must_use]
+	pub fn endorsement_peers(&self) -> CategorySet {
+		self.endorsement_domain().categories()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Archive, CategoryId, CategorySet, EndorsementDomain};
+
+	#[test]
+	fn every_current_archive_has_one_domain() {
+		for archive in Archive::ALL.iter().filter(|archive| !archive.is_legacy()) {
+			let domains: Vec<_> = EndorsementDomain::ALL
+				.iter()
+				.filter(|domain| domain.archives().contains(archive))
+				.collect();
+			assert_eq!(domains, [&archive.endorsement_domain()], "{archive}");
+		}
+	}
+
+	#[test]
+	fn legacy_archive_shares_successor_domain() {
+		for archive in Archive::ALL.iter().filter(|archive| archive.is_legacy()) {
+			let successor = archive.subsumed_into().unwrap();
+			assert_eq!(archive.endorsement_domain(), successor.endorsement_domain(), "{archive}");
+		}
+	}
+
+	#[test]
+	fn domains_partition_current_categories() {
+		let mut all = CategorySet::new();
+		for domain in EndorsementDomain::ALL {
+			let categories = domain.categories();
+			assert!(all.is_disjoint(&categories), "{domain}");
+			assert!(categories.iter().all(|category| domain.contains(category)));
+			all |= categories;
+		}
+		let current = CategorySet::all()
+			.iter()
+			.filter(|category| !category.archive().is_legacy())
+			.collect();
+		assert_eq!(all, current);
+	}
+
+	#[test]
+	fn peers() {
+		let category = CategoryId::try_from("math.AG").unwrap();
+		let peers = category.endorsement_peers();
+		assert!(peers.contains(category));
+		assert!(peers.contains(CategoryId::try_from("math-ph").unwrap()));
+		assert!(!peers.contains(CategoryId::try_from("alg-geom").unwrap()));
+	}
+}
@@ -1,4 +1,25 @@
 use crate::Archive;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// An error that can occur when parsing arXiv group identifiers
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupError {
+	/// The string is neither a group code nor a group name
+	InvalidGroup,
+}
+
+impl Error for GroupError {}
+
+impl Display for GroupError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::InvalidGroup => f.write_str("Invalid arXiv group identifier"),
+		}
+	}
+}
 
 /// A type of classification for arXiv publications
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -21,13 +42,165 @@ pub enum Group {
 	Stat,
 }
 
+impl Group {
+	/// All groups, in canonical order
+	pub const ALL: &'static [Self] = &[
+		Self::Cs,
+		Self::Econ,
+		Self::Eess,
+		Self::Math,
+		Self::Physics,
+		Self::QBio,
+		Self::QFin,
+		Self::Stat,
+	];
+
+	/// The code of the group, as used by arXiv (e.g. `q-bio`)
+	///
+	/// ```
+	/// use arxiv::Group;
+	///
+	/// assert_eq!(Group::QBio.as_str(), "q-bio");
+	/// ```
+	#[must_use]
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Cs => "cs",
+			Self::Econ => "econ",
+			Self::Eess => "eess",
+			Self::Math => "math",
+			Self::Physics => "physics",
+			Self::QBio => "q-bio",
+			Self::QFin => "q-fin",
+			Self::Stat => "stat",
+		}
+	}
+
+	/// The human-readable name of the group
+	///
+	/// ```
+	/// use arxiv::Group;
+	///
+	/// assert_eq!(Group::Eess.name(), "Electrical Engineering and Systems Science");
+	/// ```
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		match self {
+			Self::Cs => "Computer Science",
+			Self::Econ => "Economics",
+			Self::Eess => "Electrical Engineering and Systems Science",
+			Self::Math => "Mathematics",
+			Self::Physics => "Physics",
+			Self::QBio => "Quantitative Biology",
+			Self::QFin => "Quantitative Finance",
+			Self::Stat => "Statistics",
+		}
+	}
+
+	/// The archives that belong to the group, in canonical order
+	///
+	/// This includes legacy archives, which come after the current archives of the group.
+	///
+	/// ```
+	/// use arxiv::{Archive, Group};
+	///
+	/// assert_eq!(Group::Cs.archives(), &[Archive::Cs, Archive::CmpLg]);
+	/// assert!(Group::Physics.archives().contains(&Archive::HepTh));
+	/// ```
+	#[must_use]
+	pub const fn archives(&self) -> &'static [Archive] {
+		match self {
+			Self::Cs => &[Archive::Cs, Archive::CmpLg],
+			Self::Econ => &[Archive::Econ],
+			Self::Eess => &[Archive::Eess],
+			Self::Math => &[
+				Archive::Math,
+				Archive::AlgGeom,
+				Archive::DgGa,
+				Archive::FunctAn,
+				Archive::QAlg,
+			],
+			Self::Physics => &[
+				Archive::AstroPh,
+				Archive::CondMat,
+				Archive::GrQc,
+				Archive::HepEx,
+				Archive::HepLat,
+				Archive::HepPh,
+				Archive::HepTh,
+				Archive::MathPh,
+				Archive::Nlin,
+				Archive::NuclEx,
+				Archive::NuclTh,
+				Archive::Physics,
+				Archive::QuantPh,
+				Archive::AccPhys,
+				Archive::AdapOrg,
+				Archive::AoSci,
+				Archive::AtomPh,
+				Archive::BayesAn,
+				Archive::ChaoDyn,
+				Archive::ChemPh,
+				Archive::CompGas,
+				Archive::MtrlTh,
+				Archive::PattSol,
+				Archive::PlasmPh,
+				Archive::SolvInt,
+				Archive::SuprCon,
+			],
+			Self::QBio => &[Archive::QBio],
+			Self::QFin => &[Archive::QFin],
+			Self::Stat => &[Archive::Stat],
+		}
+	}
+
+	/// Converts the group to a URL where its listing page is.
+	///
+	/// Groups with a single archive share the listing page of that archive, while the physics group
+	/// leads to the listing page of the `physics` archive.
+	///
+	/// ```
+	/// use arxiv::Group;
+	/// use url::Url;
+	///
+	/// let url = Url::from(Group::QFin);
+	/// assert_eq!(url.to_string(), "https://arxiv.org/archive/q-fin");
+	/// ```
+	#[cfg(feature = "url")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+	pub fn as_url(&self) -> url::Url {
+		url::Url::from(*self)
+	}
+}
+
+impl Display for Group {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(self.as_str())
+	}
+}
+
+impl FromStr for Group {
+	type Err = GroupError;
+
+	/// Parses a group from either its code (e.g. `q-bio`) or its name (e.g. `Quantitative Biology`).
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::ALL
+			.iter()
+			.find(|group| group.as_str() == s || group.name() == s)
+			.copied()
+			.ok_or(GroupError::InvalidGroup)
+	}
+}
+
 impl From<Archive> for Group {
 	fn from(archive: Archive) -> Self {
 		match archive {
-			Archive::Cs => Self::Cs,
+			Archive::Cs | Archive::CmpLg => Self::Cs,
 			Archive::Econ => Self::Econ,
 			Archive::Eess => Self::Eess,
-			Archive::Math => Self::Math,
+			Archive::Math | Archive::AlgGeom | Archive::DgGa | Archive::FunctAn | Archive::QAlg => {
+				Self::Math
+			}
 			Archive::AstroPh
 			| Archive::CondMat
 			| Archive::GrQc
@@ -40,7 +213,20 @@ impl From<Archive> for Group {
 			| Archive::NuclEx
 			| Archive::NuclTh
 			| Archive::Physics
-			| Archive::QuantPh => Self::Physics,
+			| Archive::QuantPh
+			| Archive::AccPhys
+			| Archive::AdapOrg
+			| Archive::AoSci
+			| Archive::AtomPh
+			| Archive::BayesAn
+			| Archive::ChaoDyn
+			| Archive::ChemPh
+			| Archive::CompGas
+			| Archive::MtrlTh
+			| Archive::PattSol
+			| Archive::PlasmPh
+			| Archive::SolvInt
+			| Archive::SuprCon => Self::Physics,
 			Archive::QBio => Self::QBio,
 			Archive::QFin => Self::QFin,
 			Archive::Stat => Self::Stat,
@@ -48,13 +234,63 @@ impl From<Archive> for Group {
 	}
 }
 
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+impl From<Group> for url::Url {
+	fn from(group: Group) -> Self {
+		Self::parse(&format!("https://arxiv.org/archive/{group}")).unwrap()
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use crate::{Archive, Group};
+	use crate::{Archive, Group, GroupError};
+	use std::str::FromStr;
 
 	#[test]
 	fn group_from_archive() {
 		let cat_id = Group::from(Archive::AstroPh);
 		assert_eq!(cat_id, Group::Physics);
 	}
+
+	#[test]
+	fn archives_match_group() {
+		for group in Group::ALL {
+			assert!(group.archives().iter().all(|a| Group::from(*a) == *group));
+		}
+		let count: usize = Group::ALL.iter().map(|g| g.archives().len()).sum();
+		assert_eq!(count, Archive::ALL.len());
+	}
+
+	#[test]
+	fn display_group() {
+		assert_eq!(Group::QBio.to_string(), "q-bio");
+	}
+
+	#[test]
+	fn parse_group() {
+		assert_eq!(Group::from_str("q-fin"), Ok(Group::QFin));
+		assert_eq!(Group::from_str("Quantitative Finance"), Ok(Group::QFin));
+		assert_eq!(Group::from_str("astro-ph"), Err(GroupError::InvalidGroup));
+	}
+
+	#[test]
+	fn parse_roundtrip() {
+		for group in Group::ALL {
+			assert_eq!(Group::from_str(&group.to_string()), Ok(*group));
+		}
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "url")]
+mod tests_url_group {
+	use crate::Group;
+	use url::Url;
+
+	#[test]
+	fn url_from_group() {
+		let url = Url::from(Group::Cs);
+		assert_eq!(url.to_string(), "https://arxiv.org/archive/cs");
+	}
 }
@@ -0,0 +1,286 @@
+use crate::{Stamp, StampError};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A normalization that was applied while leniently parsing a stamp
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StampNormalization {
+	/// Runs of whitespace, as well as leading and trailing whitespace, were collapsed
+	CollapsedWhitespace,
+	/// Non-breaking or other unicode spaces were replaced with regular spaces
+	NonBreakingSpace,
+	/// Line breaks between components were replaced with spaces
+	LineBreak,
+	/// The zero padding of the day was removed (e.g. `01` to `1`)
+	ZeroPaddedDay,
+	/// The full month name was abbreviated (e.g. `January` to `Jan`)
+	FullMonthName,
+	/// The text was read in reverse, as happens with rotated text, and was turned around
+	Reversed,
+}
+
+impl Display for StampNormalization {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(match self {
+			Self::CollapsedWhitespace => "collapsed whitespace",
+			Self::NonBreakingSpace => "replaced non-breaking spaces",
+			Self::LineBreak => "replaced line breaks",
+			Self::ZeroPaddedDay => "removed zero padding of the day",
+			Self::FullMonthName => "abbreviated the month name",
+			Self::Reversed => "reversed the text",
+		})
+	}
+}
+
+/// A stamp that was parsed from text extracted out of a PDF, along with the normalizations that
+/// were needed to parse it
+///
+/// Text extraction tends to mangle the stamp on the side of a PDF: components may be separated by
+/// several spaces, non-breaking spaces or line breaks, the day may be zero-padded, the month may
+/// be spelled out, and rotated text may be read in reverse. The text is first normalized into the
+/// canonical form of a stamp, which is then parsed as strictly as [`Stamp::try_from()`] does.
+///
+/// # Examples
+/// ```
+/// use arxiv::{LenientStamp, Stamp, StampNormalization};
+///
+/// let lenient = Stamp::parse_lenient("arXiv:2001.00001\u{a0}[cs.LG]\n01 January 2000").unwrap();
+/// assert_eq!(lenient.as_str(), "arXiv:2001.00001 [cs.LG] 1 Jan 2000");
+/// assert_eq!(lenient.stamp(), Stamp::try_from("arXiv:2001.00001 [cs.LG] 1 Jan 2000").unwrap());
+/// let normalizations = lenient.normalizations();
+/// assert!(normalizations.contains(&StampNormalization::NonBreakingSpace));
+/// assert!(normalizations.contains(&StampNormalization::FullMonthName));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LenientStamp {
+	normalized: String,
+	normalizations: Vec<StampNormalization>,
+}
+
+impl LenientStamp {
+	/// The parsed stamp, which borrows from the normalized text
+	#[must_use]
+	pub fn stamp(&self) -> Stamp<'_> {
+		Stamp::try_from(self.normalized.as_str()).expect("the normalized text is a valid stamp")
+	}
+
+	/// The normalized text of the stamp, in the canonical form that arXiv prints
+	#[must_use]
+	#[inline]
+	pub fn as_str(&self) -> &str {
+		&self.normalized
+	}
+
+	/// The normalizations that were applied, in the order of [`StampNormalization`]
+	///
+	/// The slice is empty if the text already was a canonical stamp.
+	#[must_use]
+	#[inline]
+	pub fn normalizations(&self) -> &[StampNormalization] {
+		&self.normalizations
+	}
+
+	/// Checks if the text already was a canonical stamp.
+	#[must_use]
+	#[inline]
+	pub fn is_canonical(&self) -> bool {
+		self.normalizations.is_empty()
+	}
+}
+
+impl Display for LenientStamp {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(&self.normalized)
+	}
+}
+
+impl TryFrom<&str> for LenientStamp {
+	type Error = StampError;
+
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		let forward = Self::normalize(s);
+		let err = match Stamp::try_from(forward.normalized.as_str()) {
+			Ok(_) => return Ok(forward),
+			Err(err) => err,
+		};
+
+		let reversed: String = s.chars().rev().collect();
+		let mut backward = Self::normalize(&reversed);
+		match Stamp::try_from(backward.normalized.as_str()) {
+			Ok(_) => {
+				backward.normalizations.push(StampNormalization::Reversed);
+				Ok(backward)
+			}
+			Err(_) => Err(err),
+		}
+	}
+}
+
+impl LenientStamp {
+	/// Normalizes the text into the canonical form of a stamp, without checking that it is valid.
+	fn normalize(s: &str) -> Self {
+		use StampNormalization::*;
+
+		let mut normalizations = Vec::new();
+		let mut note = |normalization| {
+			if !normalizations.contains(&normalization) {
+				normalizations.push(normalization);
+			}
+		};
+
+		// collapse every run of whitespace into a single space
+		let mut tokens = Vec::new();
+		let mut rest = s;
+		while !rest.is_empty() {
+			let token_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+			let (token, after) = rest.split_at(token_end);
+			let space_end = after
+				.find(|c: char| !c.is_whitespace())
+				.unwrap_or(after.len());
+			let (space, after) = after.split_at(space_end);
+
+			if !token.is_empty() {
+				tokens.push(token);
+			}
+			if space.contains(['\n', '\r']) {
+				note(LineBreak);
+			}
+			if space.contains(|c: char| c.is_whitespace() && !c.is_ascii_whitespace()) {
+				note(NonBreakingSpace);
+			}
+			let is_edge = token.is_empty() || after.is_empty();
+			let is_single = space == "\r\n" || (space.chars().count() == 1 && space != "\t");
+			if !space.is_empty() && (is_edge || !is_single) {
+				note(CollapsedWhitespace);
+			}
+			rest = after;
+		}
+
+		// the date is always formed by the last three components
+		if let [.., day, month, _] = tokens.as_mut_slice() {
+			if let Some(unpadded) = (*day).strip_prefix('0').filter(|d| !d.is_empty()) {
+				*day = unpadded;
+				note(ZeroPaddedDay);
+			}
+			if let Some((_, abbr)) = MONTHS
+				.iter()
+				.find(|(full, abbr)| full == month && full != abbr)
+			{
+				*month = abbr;
+				note(FullMonthName);
+			}
+		}
+
+		normalizations.sort_unstable();
+		Self {
+			normalized: tokens.join(" "),
+			normalizations,
+		}
+	}
+}
+
+/// The full and abbreviated names of every month, in order
+const MONTHS: [(&str, &str); 12] = [
+	("January", "Jan"),
+	("February", "Feb"),
+	("March", "Mar"),
+	("April", "Apr"),
+	("May", "May"),
+	("June", "Jun"),
+	("July", "Jul"),
+	("August", "Aug"),
+	("September", "Sep"),
+	("October", "Oct"),
+	("November", "Nov"),
+	("December", "Dec"),
+];
+
+impl Stamp<'_> {
+	/// Parses a stamp from text extracted out of a PDF, while tolerating the ways in which text
+	/// extraction mangles it.
+	///
+	/// See [`LenientStamp`] for the variations that are accepted.
+	pub fn parse_lenient(s: &str) -> Result<LenientStamp, StampError> {
+		LenientStamp::try_from(s)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{LenientStamp, Stamp, StampError, StampNormalization::*};
+
+	const CANONICAL: &str = "arXiv:2001.00001 [cs.LG] 1 Jan 2000";
+
+	#[test]
+	fn canonical() {
+		let lenient = Stamp::parse_lenient(CANONICAL).unwrap();
+		assert!(lenient.is_canonical());
+		assert_eq!(lenient.to_string(), CANONICAL);
+	}
+
+	#[test]
+	fn whitespace() {
+		let lenient = Stamp::parse_lenient("  arXiv:2001.00001  [cs.LG]\t1 Jan 2000 ").unwrap();
+		assert_eq!(lenient.as_str(), CANONICAL);
+		assert_eq!(lenient.normalizations(), [CollapsedWhitespace]);
+
+		let lenient = Stamp::parse_lenient("arXiv:2001.00001\r\n[cs.LG]\n1\u{a0}Jan\u{202f}2000");
+		let lenient = lenient.unwrap();
+		assert_eq!(lenient.as_str(), CANONICAL);
+		assert_eq!(lenient.normalizations(), [NonBreakingSpace, LineBreak]);
+	}
+
+	#[test]
+	fn date() {
+		let lenient = Stamp::parse_lenient("arXiv:2001.00001 [cs.LG] 01 Jan 2000").unwrap();
+		assert_eq!(lenient.normalizations(), [ZeroPaddedDay]);
+
+		let lenient = Stamp::parse_lenient("arXiv:2001.00001 [cs.LG] 1 January 2000").unwrap();
+		assert_eq!(lenient.normalizations(), [FullMonthName]);
+
+		let lenient = Stamp::parse_lenient("arXiv:2001.00001 [cs.LG] 10 May 2000").unwrap();
+		assert!(lenient.is_canonical());
+	}
+
+	#[test]
+	fn reversed() {
+		let reversed: String = CANONICAL.chars().rev().collect();
+		let lenient = Stamp::parse_lenient(&reversed).unwrap();
+		assert_eq!(lenient.as_str(), CANONICAL);
+		assert_eq!(lenient.normalizations(), [Reversed]);
+
+		let forward = "arXiv:math/0309136v1\n [math.GT] 09 September  2003";
+		let reversed: String = forward.chars().rev().collect();
+		let lenient = Stamp::parse_lenient(&reversed).unwrap();
+		assert_eq!(lenient.as_str(), "arXiv:math/0309136v1 [math.GT] 9 Sep 2003");
+		assert_eq!(
+			lenient.normalizations(),
+			[
+				CollapsedWhitespace,
+				LineBreak,
+				ZeroPaddedDay,
+				FullMonthName,
+				Reversed
+			]
+		);
+	}
+
+	#[test]
+	fn old_scheme() {
+		let lenient = Stamp::parse_lenient("arXiv:hep-th/9901001v2  03 Feb 1999").unwrap();
+		assert_eq!(lenient.stamp(), Stamp::try_from("arXiv:hep-th/9901001v2 3 Feb 1999").unwrap());
+	}
+
+	#[test]
+	fn errors_are_reported_for_the_forward_text() {
+		assert_eq!(LenientStamp::try_from(""), Err(StampError::NotEnoughComponents));
+		assert_eq!(
+			Stamp::parse_lenient("arXiv:2001.00001  [cs.LG]  1 Janvier 2000"),
+			Err(StampError::InvalidDate)
+		);
+		assert_eq!(
+			Stamp::parse_lenient("arXiv:2001.00001 [cs.LG] 00 Jan 2000"),
+			Err(StampError::InvalidDate)
+		);
+	}
+}
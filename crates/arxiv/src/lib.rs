@@ -16,12 +16,12 @@
 //!
 //! ## Categories
 //! ```rust
-//! use arxiv::{Archive, CategoryId, Group};
+//! use arxiv::{Archive, AstroPhSubject, CategoryId, Group, Subject};
 //!
 //! let category = CategoryId::try_from("astro-ph.HE").unwrap();
 //! assert_eq!(category.group(), Group::Physics);
 //! assert_eq!(category.archive(), Archive::AstroPh);
-//! assert_eq!(category.subject(), "HE");
+//! assert_eq!(category.subject(), Some(Subject::AstroPh(AstroPhSubject::He)));
 //! ```
 //!
 //! ## Stamps
@@ -29,7 +29,7 @@
 //! use arxiv::{Archive, CategoryId, Stamp};
 //!
 //! let stamp = Stamp::try_from("arXiv:0706.0001v1 [q-bio.CB] 1 Jun 2007").unwrap();
-//! assert_eq!(stamp.category, CategoryId::try_new(Archive::QBio, "CB").unwrap());
+//! assert_eq!(stamp.category, CategoryId::try_new(Archive::QBio, "CB"));
 //! assert_eq!(stamp.submitted.year(), 2007);
 //! ```
 //!
@@ -37,20 +37,66 @@
 //! The crate has the following feature flags:
 //!  - `url` (default): Enables converting types into URLs where possible,
 //!    such as converting an article identifier into a URL that leads to its abstract page.
+//!  - `chrono`: Enables converting the submission date of a [`Stamp`] into `chrono` types.
+//!  - `pdf`: Enables extracting a [`Stamp`] from the first page of a PDF.
+//!  - `serde`: Enables serializing and deserializing a [`Taxonomy`] or an [`Article`] with `serde`.
+//!  - `json`: Enables loading a [`Taxonomy`] from JSON (implies `serde`).
+//!  - `time`: Enables converting the submission date of a [`Stamp`] into `time` types.
+//!  - `toml`: Enables loading a [`Taxonomy`] from TOML (implies `serde`).
+//!  - `yaml`: Enables loading and exporting a [`Taxonomy`] as YAML (implies `serde`).
 
+mod acm;
+mod active_period;
 mod archive;
+mod article;
+mod article_categories;
 mod article_id;
 mod article_version;
 mod category_id;
+mod category_set;
+mod endorsement;
 mod group;
+mod lenient_stamp;
+mod listing;
+mod msc;
+mod oai;
+#[cfg(feature = "pdf")]
+mod pdf;
+mod scan;
+mod schedule;
 mod stamp;
+mod stamp_date;
+mod subject;
+mod suggest;
+mod taxonomy;
+#[cfg(feature = "url")]
+mod url_builder;
+mod watermark;
 
+pub use crate::acm::*;
+pub use crate::active_period::*;
 pub use crate::archive::*;
+pub use crate::article::*;
+pub use crate::article_categories::*;
 pub use crate::article_id::*;
 pub use crate::article_version::*;
 pub use crate::category_id::*;
+pub use crate::category_set::*;
+pub use crate::endorsement::*;
 pub use crate::group::*;
+pub use crate::lenient_stamp::*;
+pub use crate::listing::*;
+pub use crate::msc::*;
+#[cfg(feature = "pdf")]
+pub use crate::pdf::*;
+pub use crate::scan::*;
+pub use crate::schedule::*;
 pub use crate::stamp::*;
+pub use crate::stamp_date::*;
+pub use crate::subject::*;
+pub use crate::taxonomy::*;
+#[cfg(feature = "url")]
+pub use crate::url_builder::*;
 
 /// Represents the versioned grammar that defines an arXiv identifier
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,323 @@
+use jiff::civil::Date;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[cfg(feature = "url")]
+use crate::{Archive, CategoryId, CategorySet};
+
+/// The period of announcements covered by a listing page
+///
+/// # Examples
+/// ```
+/// use arxiv::ListingPeriod;
+/// use jiff::civil::date;
+///
+/// assert_eq!(ListingPeriod::PastWeek.to_string(), "pastweek");
+/// assert_eq!(ListingPeriod::from(date(2024, 1, 15)).to_string(), "2024-01");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListingPeriod {
+	/// The articles of the most recent announcement
+	New,
+	/// The articles of the last five announcements
+	Recent,
+	/// The articles announced during the past week
+	PastWeek,
+	/// The articles announced during the month of the date
+	Month(Date),
+}
+
+impl From<Date> for ListingPeriod {
+	fn from(date: Date) -> Self {
+		Self::Month(date)
+	}
+}
+
+impl Display for ListingPeriod {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::New => f.write_str("new"),
+			Self::Recent => f.write_str("recent"),
+			Self::PastWeek => f.write_str("pastweek"),
+			Self::Month(date) => write!(f, "{:04}-{:02}", date.year(), date.month()),
+		}
+	}
+}
+
+/// A page of a listing, as a number of entries to skip and to show
+///
+/// # Examples
+/// ```
+/// use arxiv::ListingPage;
+///
+/// let page = ListingPage::new(100).next();
+/// assert_eq!(page.skip(), 100);
+/// assert_eq!(ListingPage::new(5000).show(), ListingPage::MAX_SIZE);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListingPage {
+	skip: u32,
+	show: u16,
+}
+
+impl ListingPage {
+	/// The largest number of entries that arXiv shows on a single page
+	pub const MAX_SIZE: u16 = 2000;
+
+	/// Creates the first page showing the given number of entries, which is clamped between 1
+	/// and [`Self::MAX_SIZE`].
+	#[must_use]
+	pub const fn new(show: u16) -> Self {
+		let show = match show {
+			0 => 1,
+			Self::MAX_SIZE.. => Self::MAX_SIZE,
+			_ => show,
+		};
+		Self { skip: 0, show }
+	}
+
+	/// The page that starts after skipping the given number of entries.
+	#[must_use]
+	pub const fn with_skip(self, skip: u32) -> Self {
+		Self { skip, ..self }
+	}
+
+	/// The page that follows this one.
+	#[must_use]
+	pub const fn next(self) -> Self {
+		self.with_skip(self.skip.saturating_add(self.show as u32))
+	}
+
+	/// The number of entries skipped before the page
+	#[must_use]
+	#[inline]
+	pub const fn skip(&self) -> u32 {
+		self.skip
+	}
+
+	/// The number of entries shown on the page
+	#[must_use]
+	#[inline]
+	pub const fn show(&self) -> u16 {
+		self.show
+	}
+}
+
+/// The format of a feed of new announcements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedFormat {
+	/// RSS 2.0
+	Rss,
+	/// Atom 1.0
+	Atom,
+}
+
+impl FeedFormat {
+	/// The path segment of the format, as used by arXiv (e.g. `rss`)
+	#[must_use]
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Rss => "rss",
+			Self::Atom => "atom",
+		}
+	}
+}
+
+impl Display for FeedFormat {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(self.as_str())
+	}
+}
+
+#[cfg(feature = "url")]
+fn listing_url(id: &dyn Display, period: ListingPeriod, page: Option<ListingPage>) -> url::Url {
+	let mut url = url::Url::parse(&format!("https://arxiv.org/list/{id}/{period}")).unwrap();
+	if let Some(page) = page {
+		url.query_pairs_mut()
+			.append_pair("skip", &page.skip.to_string())
+			.append_pair("show", &page.show.to_string());
+	}
+	url
+}
+
+#[cfg(feature = "url")]
+fn feed_url(ids: &dyn Display, format: FeedFormat) -> url::Url {
+	url::Url::parse(&format!("https://rss.arxiv.org/{format}/{ids}")).unwrap()
+}
+
+#[cfg(feature = "url")]
+fn catchup_url(id: &dyn Display, since: Date) -> url::Url {
+	url::Url::parse(&format!("https://arxiv.org/catchup/{id}/{since}")).unwrap()
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+impl Archive {
+	/// The URL of a listing page of the archive
+	///
+	/// ```
+	/// use arxiv::{Archive, ListingPeriod};
+	///
+	/// let url = Archive::HepTh.listing_url(ListingPeriod::Recent, None);
+	/// assert_eq!(url.as_str(), "https://arxiv.org/list/hep-th/recent");
+	/// ```
+	#[must_use]
+	pub fn listing_url(&self, period: ListingPeriod, page: Option<ListingPage>) -> url::Url {
+		listing_url(self, period, page)
+	}
+
+	/// The URL of the feed of new announcements in the archive
+	#[must_use]
+	pub fn feed_url(&self, format: FeedFormat) -> url::Url {
+		feed_url(self, format)
+	}
+
+	/// The URL of the page catching up on the announcements in the archive since the given date
+	#[must_use]
+	pub fn catchup_url(&self, since: Date) -> url::Url {
+		catchup_url(self, since)
+	}
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+impl CategoryId {
+	/// The URL of a listing page of the category
+	///
+	/// ```
+	/// use arxiv::{CategoryId, ListingPage, ListingPeriod};
+	/// use jiff::civil::date;
+	///
+	/// let category = CategoryId::try_from("cs.LG").unwrap();
+	/// let url = category.listing_url(ListingPeriod::New, None);
+	/// assert_eq!(url.as_str(), "https://arxiv.org/list/cs.LG/new");
+	///
+	/// let page = ListingPage::new(250).next();
+	/// let url = category.listing_url(date(2024, 1, 1).into(), Some(page));
+	/// assert_eq!(url.as_str(), "https://arxiv.org/list/cs.LG/2024-01?skip=250&show=250");
+	/// ```
+	#[must_use]
+	pub fn listing_url(&self, period: ListingPeriod, page: Option<ListingPage>) -> url::Url {
+		listing_url(self, period, page)
+	}
+
+	/// The URL of the feed of new announcements in the category
+	///
+	/// ```
+	/// use arxiv::{CategoryId, FeedFormat};
+	///
+	/// let category = CategoryId::try_from("cs.LG").unwrap();
+	/// let url = category.feed_url(FeedFormat::Rss);
+	/// assert_eq!(url.as_str(), "https://rss.arxiv.org/rss/cs.LG");
+	/// ```
+	#[must_use]
+	pub fn feed_url(&self, format: FeedFormat) -> url::Url {
+		feed_url(self, format)
+	}
+
+	/// The URL of the page catching up on the announcements in the category since the given date
+	///
+	/// ```
+	/// use arxiv::CategoryId;
+	/// use jiff::civil::date;
+	///
+	/// let category = CategoryId::try_from("cs.LG").unwrap();
+	/// let url = category.catchup_url(date(2024, 10, 7));
+	/// assert_eq!(url.as_str(), "https://arxiv.org/catchup/cs.LG/2024-10-07");
+	/// ```
+	#[must_use]
+	pub fn catchup_url(&self, since: Date) -> url::Url {
+		catchup_url(self, since)
+	}
+
+	/// The URL of the category's entry in arXiv's category taxonomy
+	///
+	/// ```
+	/// use arxiv::CategoryId;
+	///
+	/// let category = CategoryId::try_from("q-fin.TR").unwrap();
+	/// let url = category.taxonomy_url();
+	/// assert_eq!(url.as_str(), "https://arxiv.org/category_taxonomy#q-fin.TR");
+	/// ```
+	#[must_use]
+	pub fn taxonomy_url(&self) -> url::Url {
+		let mut url = url::Url::parse("https://arxiv.org/category_taxonomy").unwrap();
+		url.set_fragment(Some(&self.to_string()));
+		url
+	}
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+impl CategorySet {
+	/// The URL of the combined feed of new announcements in all categories of the set, unless the
+	/// set is empty
+	///
+	/// ```
+	/// use arxiv::{CategorySet, FeedFormat};
+	///
+	/// let set = CategorySet::try_from("stat.ML cs.LG").unwrap();
+	/// let url = set.feed_url(FeedFormat::Atom).unwrap();
+	/// assert_eq!(url.as_str(), "https://rss.arxiv.org/atom/cs.LG+stat.ML");
+	///
+	/// assert_eq!(CategorySet::new().feed_url(FeedFormat::Atom), None);
+	/// ```
+	#[must_use]
+	pub fn feed_url(&self, format: FeedFormat) -> Option<url::Url> {
+		if self.is_empty() {
+			return None;
+		}
+		let ids = self
+			.iter()
+			.map(|category| category.to_string())
+			.collect::<Vec<_>>()
+			.join("+");
+		Some(feed_url(&ids, format))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{ListingPage, ListingPeriod};
+	use jiff::civil::date;
+
+	#[test]
+	fn period_display() {
+		assert_eq!(ListingPeriod::New.to_string(), "new");
+		assert_eq!(ListingPeriod::Recent.to_string(), "recent");
+		assert_eq!(ListingPeriod::Month(date(1999, 9, 30)).to_string(), "1999-09");
+	}
+
+	#[test]
+	fn page_bounds() {
+		assert_eq!(ListingPage::new(0).show(), 1);
+		assert_eq!(ListingPage::new(2000).show(), 2000);
+		assert_eq!(ListingPage::new(25).with_skip(u32::MAX).next().skip(), u32::MAX);
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "url")]
+mod tests_url {
+	use crate::{Archive, CategoryId, FeedFormat, ListingPage, ListingPeriod};
+	use jiff::civil::date;
+
+	#[test]
+	fn archive_urls() {
+		let url = Archive::AstroPh.listing_url(ListingPeriod::PastWeek, Some(ListingPage::new(25)));
+		assert_eq!(url.as_str(), "https://arxiv.org/list/astro-ph/pastweek?skip=0&show=25");
+
+		let url = Archive::AstroPh.feed_url(FeedFormat::Atom);
+		assert_eq!(url.as_str(), "https://rss.arxiv.org/atom/astro-ph");
+
+		let url = Archive::Math.catchup_url(date(2025, 2, 3));
+		assert_eq!(url.as_str(), "https://arxiv.org/catchup/math/2025-02-03");
+	}
+
+	#[test]
+	fn category_without_subject() {
+		let category = CategoryId::try_from("hep-th").unwrap();
+		let url = category.listing_url(ListingPeriod::New, None);
+		assert_eq!(url.as_str(), "https://arxiv.org/list/hep-th/new");
+		assert_eq!(category.taxonomy_url().fragment(), Some("hep-th"));
+	}
+}
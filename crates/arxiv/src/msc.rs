@@ -0,0 +1,561 @@
+use crate::{CategoryId, MathSubject, Subject};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// An error that can occur when parsing and validating MSC codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MscError<'a> {
+	/// The code is not formatted like an MSC code
+	InvalidCode(&'a str),
+	/// The code is well-formed, but its top-level class does not exist in MSC2020
+	UnknownClass(&'a str),
+}
+
+impl Error for MscError<'_> {}
+
+impl Display for MscError<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::InvalidCode(s) => write!(f, "Invalid MSC code: {s}"),
+			Self::UnknownClass(s) => {
+				write!(f, "The MSC code \"{s}\" has no MSC2020 top-level class")
+			}
+		}
+	}
+}
+
+/// A class of the [Mathematics Subject Classification][msc] (MSC2020)
+///
+/// MSC codes come in three levels:
+///  - top-level classes, written as `14` or `14-XX`;
+///  - second-level classes, written as `14Jxx`;
+///  - third-level classes, written as `14J32`, or as `14-01` for the classes shared by every
+///    top-level class (such as introductory texts and research exposition).
+///
+/// # Examples
+/// ```
+/// use arxiv::{CategoryId, MscCode};
+///
+/// let code = MscCode::try_from("14J32").unwrap();
+/// assert_eq!(code.class(), 14);
+/// assert_eq!(code.subclass(), Some('J'));
+/// assert_eq!(code.leaf(), Some(32));
+/// assert_eq!(code.top_level().to_string(), "14-XX");
+/// assert_eq!(code.name(), Some("Algebraic geometry"));
+/// assert_eq!(code.category(), Some(CategoryId::try_from("math.AG").unwrap()));
+/// ```
+///
+/// [msc]: <https://zbmath.org/classification/>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MscCode {
+	class: u8,
+	subclass: Option<u8>,
+	leaf: Option<u8>,
+}
+
+impl MscCode {
+	const fn new(class: u8, subclass: Option<u8>, leaf: Option<u8>) -> Self {
+		Self {
+			class,
+			subclass,
+			leaf,
+		}
+	}
+
+	const fn top(class: u8) -> Self {
+		Self::new(class, None, None)
+	}
+
+	const fn second(class: u8, subclass: u8) -> Self {
+		Self::new(class, Some(subclass), None)
+	}
+
+	const fn third(class: u8, subclass: u8, leaf: u8) -> Self {
+		Self::new(class, Some(subclass), Some(leaf))
+	}
+
+	/// The two-digit top-level class (e.g. `14` in `14J32`)
+	#[must_use]
+	#[inline]
+	pub const fn class(&self) -> u8 {
+		self.class
+	}
+
+	/// The second-level letter (e.g. `J` in `14J32`), which is `-` for the classes shared by
+	/// every top-level class (e.g. `14-01`)
+	#[must_use]
+	#[inline]
+	pub const fn subclass(&self) -> Option<char> {
+		match self.subclass {
+			Some(c) => Some(c as char),
+			None => None,
+		}
+	}
+
+	/// The two-digit third-level number (e.g. `32` in `14J32`)
+	#[must_use]
+	#[inline]
+	pub const fn leaf(&self) -> Option<u8> {
+		self.leaf
+	}
+
+	/// Checks if the code is a top-level class, such as `14-XX`.
+	#[must_use]
+	#[inline]
+	pub const fn is_top_level(&self) -> bool {
+		self.subclass.is_none()
+	}
+
+	/// The top-level class that the code falls under.
+	#[must_use]
+	#[inline]
+	pub const fn top_level(&self) -> Self {
+		Self::top(self.class)
+	}
+
+	/// Checks if the other code is equal to or falls under this one.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::MscCode;
+	///
+	/// let ag = MscCode::try_from("14-XX").unwrap();
+	/// assert!(ag.contains(&MscCode::try_from("14J32").unwrap()));
+	/// assert!(!ag.contains(&MscCode::try_from("11F11").unwrap()));
+	/// ```
+	#[must_use]
+	pub fn contains(&self, other: &Self) -> bool {
+		self.class == other.class
+			&& self.subclass.is_none_or(|c| other.subclass == Some(c))
+			&& self.leaf.is_none_or(|n| other.leaf == Some(n))
+	}
+
+	/// The name of the top-level class that the code falls under.
+	#[must_use]
+	pub fn name(&self) -> Option<&'static str> {
+		CLASSES
+			.iter()
+			.find(|(class, _)| *class == self.class)
+			.map(|(_, name)| *name)
+	}
+
+	/// The `math` category that most closely corresponds to the code, if any.
+	///
+	/// When the code falls under several mapped classes, the most specific one wins; for example,
+	/// `46L05` maps to `math.OA` rather than `math.FA`. Codes outside of mathematics proper (such
+	/// as `76` for fluid mechanics) have no corresponding category.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{CategoryId, MscCode};
+	///
+	/// let code = MscCode::try_from("46L05").unwrap();
+	/// assert_eq!(code.category(), Some(CategoryId::try_from("math.OA").unwrap()));
+	/// assert_eq!(MscCode::try_from("76D05").unwrap().category(), None);
+	/// ```
+	#[must_use]
+	pub fn category(&self) -> Option<CategoryId> {
+		MathSubject::ALL
+			.iter()
+			.flat_map(|subject| subject.msc_classes().iter().map(move |msc| (*subject, msc)))
+			.filter(|(_, msc)| msc.contains(self))
+			.max_by_key(|(_, msc)| (msc.subclass.is_some(), msc.leaf.is_some()))
+			.map(|(subject, _)| CategoryId::from(subject))
+	}
+
+	fn parse(s: &str) -> Option<Self> {
+		let class = parse_digits(s.get(..2)?)?;
+		match s.as_bytes()[2..] {
+			[] => Some(Self::top(class)),
+			[b'-', x1, x2] if x1.eq_ignore_ascii_case(&b'x') && x2.eq_ignore_ascii_case(&b'x') => {
+				Some(Self::top(class))
+			}
+			[c, x1, x2] if c.is_ascii_uppercase() && x1 == x2 && x1.eq_ignore_ascii_case(&b'x') => {
+				Some(Self::second(class, c))
+			}
+			[c @ (b'A'..=b'Z' | b'-'), ..] => Some(Self::third(class, c, parse_digits(&s[3..])?)),
+			_ => None,
+		}
+	}
+}
+
+fn parse_digits(s: &str) -> Option<u8> {
+	match s.as_bytes() {
+		[a, b] if a.is_ascii_digit() && b.is_ascii_digit() => Some((a - b'0') * 10 + (b - b'0')),
+		_ => None,
+	}
+}
+
+impl<'a> TryFrom<&'a str> for MscCode {
+	type Error = MscError<'a>;
+
+	fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+		let code = Self::parse(s).ok_or(MscError::InvalidCode(s))?;
+		match code.name() {
+			Some(_) => Ok(code),
+			None => Err(MscError::UnknownClass(s)),
+		}
+	}
+}
+
+impl Display for MscCode {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match (self.subclass(), self.leaf) {
+			(None, _) => write!(f, "{:02}-XX", self.class),
+			(Some(c), None) => write!(f, "{:02}{c}xx", self.class),
+			(Some(c), Some(n)) => write!(f, "{:02}{c}{n:02}", self.class),
+		}
+	}
+}
+
+/// The MSC codes of an article, as listed in the `msc-class` field of its arXiv metadata
+///
+/// The field is free-form, so the common ways of marking codes as primary or secondary are all
+/// accepted:
+///  - `Primary 11F11; Secondary 11F30, 11F37`
+///  - `14J32 (Primary), 14D07, 32G20 (Secondary)`
+///  - `05C50, 15A18`, where the first code is primary and the rest are secondary
+///
+/// # Examples
+/// ```
+/// use arxiv::{MscClasses, MscCode};
+///
+/// let classes = MscClasses::try_from("Primary 11F11; Secondary 11F30, 11F37").unwrap();
+/// assert_eq!(classes.primary, [MscCode::try_from("11F11").unwrap()]);
+/// assert_eq!(classes.secondary.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MscClasses {
+	/// The primary classes of the article
+	pub primary: Vec<MscCode>,
+	/// The secondary classes of the article
+	pub secondary: Vec<MscCode>,
+}
+
+impl MscClasses {
+	/// An iterator over all codes, primary ones first.
+	pub fn iter(&self) -> impl Iterator<Item = &MscCode> {
+		self.primary.iter().chain(&self.secondary)
+	}
+}
+
+impl<'a> TryFrom<&'a str> for MscClasses {
+	type Error = MscError<'a>;
+
+	fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+		let mut classes = Self::default();
+		// codes seen outside of a "Primary"/"Secondary" prefix, waiting for a "(Primary)" suffix
+		let mut pending = Vec::new();
+		let mut prefix: Option<bool> = None;
+		let mut marked = false;
+
+		for token in s.split(|c: char| c.is_whitespace() || c == ',' || c == ';') {
+			let word = token.trim_end_matches([':', '.']);
+			let is_suffix = word.starts_with('(');
+			let role = match word.trim_matches(['(', ')']).to_ascii_lowercase().as_str() {
+				"" | "and" => continue,
+				"primary" => Some(true),
+				"secondary" => Some(false),
+				_ => None,
+			};
+
+			match (role, is_suffix) {
+				(Some(primary), true) => {
+					marked = true;
+					let codes = std::mem::take(&mut pending);
+					match primary {
+						true => classes.primary.extend(codes),
+						false => classes.secondary.extend(codes),
+					}
+				}
+				(Some(primary), false) => {
+					marked = true;
+					classes.secondary.append(&mut pending);
+					prefix = Some(primary);
+				}
+				(None, _) => {
+					let code = MscCode::try_from(word)?;
+					match prefix {
+						Some(true) => classes.primary.push(code),
+						Some(false) => classes.secondary.push(code),
+						None => pending.push(code),
+					}
+				}
+			}
+		}
+
+		if !marked && !pending.is_empty() {
+			classes.primary.push(pending.remove(0));
+		}
+		classes.secondary.append(&mut pending);
+		Ok(classes)
+	}
+}
+
+impl MathSubject {
+	/// The MSC2020 classes that roughly correspond to the subject class, as described by arXiv's
+	/// category taxonomy.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{MathSubject, MscCode};
+	///
+	/// assert_eq!(MathSubject::Ag.msc_classes(), [MscCode::try_from("14").unwrap()]);
+	/// ```
+	#[must_use]
+	pub const fn msc_classes(&self) -> &'static [MscCode] {
+		MATH_CLASSES[*self as usize].1
+	}
+}
+
+impl CategoryId {
+	/// The MSC2020 classes that roughly correspond to the category, which are only known for
+	/// categories of the [`Archive::Math`](crate::Archive::Math) archive.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{CategoryId, MscCode};
+	///
+	/// let category = CategoryId::try_from("math.NT").unwrap();
+	/// assert!(category.msc_classes().contains(&MscCode::try_from("11-XX").unwrap()));
+	/// assert!(CategoryId::try_from("cs.LG").unwrap().msc_classes().is_empty());
+	/// ```
+	#[must_use]
+	pub const fn msc_classes(&self) -> &'static [MscCode] {
+		match self.subject() {
+			Some(Subject::Math(subject)) => subject.msc_classes(),
+			_ => &[],
+		}
+	}
+}
+
+/// The MSC2020 classes of each `math` subject class, in the same order as [`MathSubject::ALL`]
+const MATH_CLASSES: &[(MathSubject, &[MscCode])] = {
+	use MathSubject::*;
+	const fn top(class: u8) -> MscCode {
+		MscCode::top(class)
+	}
+	&[
+		(Ac, &[top(13)]),
+		(Ag, &[top(14)]),
+		(Ap, &[top(35)]),
+		(At, &[top(55)]),
+		(
+			Ca,
+			&[
+				top(26),
+				top(28),
+				top(31),
+				top(33),
+				top(34),
+				top(39),
+				top(40),
+				top(41),
+				top(42),
+				top(44),
+				top(45),
+			],
+		),
+		(Co, &[top(5)]),
+		(Ct, &[top(18)]),
+		(Cv, &[top(30), top(32)]),
+		(Dg, &[top(53), top(58)]),
+		(Ds, &[top(37)]),
+		(Fa, &[top(43), top(46), top(47)]),
+		(Gm, &[top(0)]),
+		(Gn, &[top(54)]),
+		(Gr, &[top(20)]),
+		(Gt, &[top(57)]),
+		(Ho, &[top(1), top(97)]),
+		(It, &[top(94)]),
+		(Kt, &[top(19)]),
+		(Lo, &[top(3)]),
+		(Mg, &[top(51), top(52)]),
+		(Mp, &[top(70), top(81), top(82), top(83)]),
+		(Na, &[top(65)]),
+		(Nt, &[top(11), top(12)]),
+		(Oa, &[MscCode::second(46, b'L')]),
+		(Oc, &[top(49), top(90), top(93)]),
+		(Pr, &[top(60)]),
+		(
+			Qa,
+			&[
+				MscCode::second(16, b'T'),
+				MscCode::third(17, b'B', 37),
+				MscCode::third(81, b'R', 50),
+			],
+		),
+		(Ra, &[top(6), top(8), top(15), top(16), top(17)]),
+		(Rt, &[MscCode::second(20, b'C'), top(22)]),
+		(Sg, &[MscCode::second(53, b'D')]),
+		(Sp, &[MscCode::second(35, b'P'), MscCode::second(47, b'A')]),
+		(St, &[top(62)]),
+	]
+};
+
+/// The top-level classes of MSC2020 and their names
+const CLASSES: &[(u8, &str)] = &[
+	(0, "General and overarching topics; collections"),
+	(1, "History and biography"),
+	(3, "Mathematical logic and foundations"),
+	(5, "Combinatorics"),
+	(6, "Order, lattices, ordered algebraic structures"),
+	(8, "General algebraic systems"),
+	(11, "Number theory"),
+	(12, "Field theory and polynomials"),
+	(13, "Commutative algebra"),
+	(14, "Algebraic geometry"),
+	(15, "Linear and multilinear algebra; matrix theory"),
+	(16, "Associative rings and algebras"),
+	(17, "Nonassociative rings and algebras"),
+	(18, "Category theory; homological algebra"),
+	(19, "K-theory"),
+	(20, "Group theory and generalizations"),
+	(22, "Topological groups, Lie groups"),
+	(26, "Real functions"),
+	(28, "Measure and integration"),
+	(30, "Functions of a complex variable"),
+	(31, "Potential theory"),
+	(32, "Several complex variables and analytic spaces"),
+	(33, "Special functions"),
+	(34, "Ordinary differential equations"),
+	(35, "Partial differential equations"),
+	(37, "Dynamical systems and ergodic theory"),
+	(39, "Difference and functional equations"),
+	(40, "Sequences, series, summability"),
+	(41, "Approximations and expansions"),
+	(42, "Harmonic analysis on Euclidean spaces"),
+	(43, "Abstract harmonic analysis"),
+	(44, "Integral transforms, operational calculus"),
+	(45, "Integral equations"),
+	(46, "Functional analysis"),
+	(47, "Operator theory"),
+	(49, "Calculus of variations and optimal control; optimization"),
+	(51, "Geometry"),
+	(52, "Convex and discrete geometry"),
+	(53, "Differential geometry"),
+	(54, "General topology"),
+	(55, "Algebraic topology"),
+	(57, "Manifolds and cell complexes"),
+	(58, "Global analysis, analysis on manifolds"),
+	(60, "Probability theory and stochastic processes"),
+	(62, "Statistics"),
+	(65, "Numerical analysis"),
+	(68, "Computer science"),
+	(70, "Mechanics of particles and systems"),
+	(74, "Mechanics of deformable solids"),
+	(76, "Fluid mechanics"),
+	(78, "Optics, electromagnetic theory"),
+	(80, "Classical thermodynamics, heat transfer"),
+	(81, "Quantum theory"),
+	(82, "Statistical mechanics, structure of matter"),
+	(83, "Relativity and gravitational theory"),
+	(85, "Astronomy and astrophysics"),
+	(86, "Geophysics"),
+	(90, "Operations research, mathematical programming"),
+	(91, "Game theory, economics, finance, and other social and behavioral sciences"),
+	(92, "Biology and other natural sciences"),
+	(93, "Systems theory; control"),
+	(94, "Information and communication theory, circuits"),
+	(97, "Mathematics education"),
+];
+
+#[cfg(test)]
+mod tests {
+	use super::MATH_CLASSES;
+	use crate::{CategoryId, MathSubject, MscClasses, MscCode, MscError};
+
+	#[test]
+	fn parse_levels() {
+		assert_eq!(MscCode::try_from("14"), Ok(MscCode::top(14)));
+		assert_eq!(MscCode::try_from("14-XX"), Ok(MscCode::top(14)));
+		assert_eq!(MscCode::try_from("14Jxx"), Ok(MscCode::second(14, b'J')));
+		assert_eq!(MscCode::try_from("14J32"), Ok(MscCode::third(14, b'J', 32)));
+		assert_eq!(MscCode::try_from("14-01"), Ok(MscCode::third(14, b'-', 1)));
+		assert_eq!(MscCode::try_from("05C50"), Ok(MscCode::third(5, b'C', 50)));
+	}
+
+	#[test]
+	fn parse_invalid() {
+		assert_eq!(MscCode::try_from("14J3"), Err(MscError::InvalidCode("14J3")));
+		assert_eq!(MscCode::try_from("14jxx"), Err(MscError::InvalidCode("14jxx")));
+		assert_eq!(MscCode::try_from("1"), Err(MscError::InvalidCode("1")));
+		assert_eq!(MscCode::try_from("14J32a"), Err(MscError::InvalidCode("14J32a")));
+		assert_eq!(MscCode::try_from("99A05"), Err(MscError::UnknownClass("99A05")));
+	}
+
+	#[test]
+	fn display_roundtrip() {
+		for s in ["14-XX", "14Jxx", "14J32", "14-01", "05C50"] {
+			assert_eq!(MscCode::try_from(s).unwrap().to_string(), s);
+		}
+	}
+
+	#[test]
+	fn every_subject_is_mapped() {
+		let subjects: Vec<_> = MATH_CLASSES.iter().map(|(subject, _)| *subject).collect();
+		assert_eq!(subjects, MathSubject::ALL);
+		for subject in MathSubject::ALL {
+			assert!(!subject.msc_classes().is_empty(), "{subject}");
+			for code in subject.msc_classes() {
+				assert!(code.name().is_some(), "{code}");
+			}
+		}
+	}
+
+	#[test]
+	fn mapping_is_bidirectional() {
+		for subject in MathSubject::ALL {
+			for code in subject.msc_classes() {
+				assert_eq!(code.category(), Some(CategoryId::from(*subject)), "{code}");
+			}
+		}
+	}
+
+	#[test]
+	fn category_prefers_specific_class() {
+		let category = |s| {
+			MscCode::try_from(s)
+				.unwrap()
+				.category()
+				.unwrap()
+				.to_string()
+		};
+		assert_eq!(category("53C21"), "math.DG");
+		assert_eq!(category("53D05"), "math.SG");
+		assert_eq!(category("81T40"), "math.MP");
+		assert_eq!(category("81R50"), "math.QA");
+	}
+
+	#[test]
+	fn classes_prefix_markers() {
+		let classes = MscClasses::try_from("Primary: 11F11, 11F12; Secondary: 11F30").unwrap();
+		assert_eq!(classes.primary, [MscCode::third(11, b'F', 11), MscCode::third(11, b'F', 12)]);
+		assert_eq!(classes.secondary, [MscCode::third(11, b'F', 30)]);
+	}
+
+	#[test]
+	fn classes_suffix_markers() {
+		let classes = MscClasses::try_from("14J32 (Primary), 14D07, 32G20 (Secondary)").unwrap();
+		assert_eq!(classes.primary, [MscCode::third(14, b'J', 32)]);
+		assert_eq!(classes.secondary, [MscCode::third(14, b'D', 7), MscCode::third(32, b'G', 20)]);
+
+		let classes = MscClasses::try_from("14J32 (primary); 14D07").unwrap();
+		assert_eq!(classes.secondary, [MscCode::third(14, b'D', 7)]);
+	}
+
+	#[test]
+	fn classes_unmarked() {
+		let classes = MscClasses::try_from("05C50, 15A18 and 15A42").unwrap();
+		assert_eq!(classes.primary, [MscCode::third(5, b'C', 50)]);
+		assert_eq!(classes.secondary.len(), 2);
+		assert_eq!(classes.iter().count(), 3);
+
+		assert_eq!(MscClasses::try_from(""), Ok(MscClasses::default()));
+	}
+
+	#[test]
+	fn classes_invalid() {
+		assert_eq!(MscClasses::try_from("14J32, foo"), Err(MscError::InvalidCode("foo")));
+	}
+}
@@ -0,0 +1,184 @@
+use crate::{Archive, CategoryId, Group};
+
+/// The separator between the levels of a set spec
+const SET_DELIM: char = ':';
+
+impl Group {
+	/// The [OAI-PMH] set spec of the group (e.g. `q-bio`)
+	///
+	/// ```
+	/// use arxiv::Group;
+	///
+	/// assert_eq!(Group::QBio.oai_set_spec(), "q-bio");
+	/// ```
+	///
+	/// [OAI-PMH]: <https://info.arxiv.org/help/oa/index.html>
+	#[must_use]
+	#[inline]
+	pub const fn oai_set_spec(&self) -> &'static str {
+		self.as_str()
+	}
+
+	/// Parses the OAI-PMH set spec of a group.
+	///
+	/// ```
+	/// use arxiv::Group;
+	///
+	/// assert_eq!(Group::from_oai_set_spec("physics"), Some(Group::Physics));
+	/// assert_eq!(Group::from_oai_set_spec("physics:hep-th"), None);
+	/// ```
+	#[must_use]
+	pub fn from_oai_set_spec(s: &str) -> Option<Self> {
+		Self::ALL.iter().copied().find(|group| group.as_str() == s)
+	}
+}
+
+impl Archive {
+	/// The OAI-PMH set spec of the archive (e.g. `physics:hep-th` or `cs`)
+	///
+	/// Legacy archives have no set of their own, since their articles are harvested under the
+	/// sets of the archives they were subsumed into.
+	///
+	/// ```
+	/// use arxiv::Archive;
+	///
+	/// assert_eq!(Archive::HepTh.oai_set_spec().as_deref(), Some("physics:hep-th"));
+	/// assert_eq!(Archive::Math.oai_set_spec().as_deref(), Some("math"));
+	/// assert_eq!(Archive::ChaoDyn.oai_set_spec(), None);
+	/// ```
+	#[must_use]
+	pub fn oai_set_spec(&self) -> Option<String> {
+		if self.is_legacy() {
+			return None;
+		}
+		match Group::from(*self) {
+			Group::Physics => Some(format!("{}{SET_DELIM}{self}", Group::Physics)),
+			group => Some(group.oai_set_spec().to_owned()),
+		}
+	}
+
+	/// Parses the OAI-PMH set spec of an archive.
+	///
+	/// The archives outside of the physics group are also accepted when named after both their
+	/// group and themselves (e.g. `cs:cs`).
+	///
+	/// ```
+	/// use arxiv::Archive;
+	///
+	/// assert_eq!(Archive::from_oai_set_spec("physics:astro-ph"), Some(Archive::AstroPh));
+	/// assert_eq!(Archive::from_oai_set_spec("q-bio"), Some(Archive::QBio));
+	/// assert_eq!(Archive::from_oai_set_spec("physics"), None);
+	/// ```
+	#[must_use]
+	pub fn from_oai_set_spec(s: &str) -> Option<Self> {
+		let (group, archive) = match s.split_once(SET_DELIM) {
+			Some((group, archive)) => (Group::from_oai_set_spec(group)?, archive),
+			None => (Group::from_oai_set_spec(s)?, s),
+		};
+		let archive = Self::from_code(archive).filter(|archive| !archive.is_legacy())?;
+		let matches = match s.contains(SET_DELIM) {
+			true => Group::from(archive) == group,
+			false => archive.oai_set_spec()? == s,
+		};
+		matches.then_some(archive)
+	}
+}
+
+impl CategoryId {
+	/// The OAI-PMH set spec of the category (e.g. `cs:cs:LG`)
+	///
+	/// The category of an archive itself shares the set of the archive.
+	///
+	/// ```
+	/// use arxiv::CategoryId;
+	///
+	/// let category = CategoryId::try_from("astro-ph.CO").unwrap();
+	/// assert_eq!(category.oai_set_spec().as_deref(), Some("physics:astro-ph:CO"));
+	///
+	/// let category = CategoryId::try_from("hep-th").unwrap();
+	/// assert_eq!(category.oai_set_spec().as_deref(), Some("physics:hep-th"));
+	/// ```
+	#[must_use]
+	pub fn oai_set_spec(&self) -> Option<String> {
+		let archive = self.archive().oai_set_spec()?;
+		Some(match self.subject() {
+			Some(subject) => {
+				format!("{}{SET_DELIM}{}{SET_DELIM}{subject}", self.group(), self.archive())
+			}
+			None => archive,
+		})
+	}
+
+	/// Parses the OAI-PMH set spec of a category.
+	///
+	/// ```
+	/// use arxiv::CategoryId;
+	///
+	/// let category = CategoryId::from_oai_set_spec("cs:cs:LG").unwrap();
+	/// assert_eq!(category, CategoryId::try_from("cs.LG").unwrap());
+	///
+	/// // the set of an archive is the set of its own category
+	/// let category = CategoryId::from_oai_set_spec("physics:astro-ph").unwrap();
+	/// assert_eq!(category, CategoryId::try_from("astro-ph").unwrap());
+	/// assert!(CategoryId::from_oai_set_spec("physics").is_none());
+	/// ```
+	#[must_use]
+	pub fn from_oai_set_spec(s: &str) -> Option<Self> {
+		match s.rsplit_once(SET_DELIM) {
+			Some((archive, subject)) if archive.contains(SET_DELIM) => {
+				let archive = Archive::from_oai_set_spec(archive)?;
+				Self::try_new(archive, subject).filter(|_| !subject.is_empty())
+			}
+			_ => Self::from_archive(Archive::from_oai_set_spec(s)?),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Archive, CategoryId, CategorySet, Group};
+
+	#[test]
+	fn group_roundtrip() {
+		for group in Group::ALL {
+			assert_eq!(Group::from_oai_set_spec(group.oai_set_spec()), Some(*group));
+		}
+	}
+
+	#[test]
+	fn archive_roundtrip() {
+		for archive in Archive::ALL.iter().filter(|archive| !archive.is_legacy()) {
+			let spec = archive.oai_set_spec().unwrap();
+			assert_eq!(Archive::from_oai_set_spec(&spec), Some(*archive), "{spec}");
+		}
+	}
+
+	#[test]
+	fn category_roundtrip() {
+		for category in &CategorySet::all() {
+			match category.oai_set_spec() {
+				Some(spec) => assert_eq!(CategoryId::from_oai_set_spec(&spec), Some(category)),
+				None => assert!(category.archive().is_legacy(), "{category}"),
+			}
+		}
+	}
+
+	#[test]
+	fn archive_named_after_group() {
+		assert_eq!(Archive::from_oai_set_spec("cs:cs"), Some(Archive::Cs));
+		assert_eq!(Archive::from_oai_set_spec("physics:physics"), Some(Archive::Physics));
+		assert_eq!(Archive::from_oai_set_spec("hep-th"), None);
+		assert_eq!(Archive::from_oai_set_spec("cs:hep-th"), None);
+		assert_eq!(Archive::from_oai_set_spec("physics:cs"), None);
+		assert_eq!(Archive::from_oai_set_spec("physics:chao-dyn"), None);
+	}
+
+	#[test]
+	fn invalid_category_specs() {
+		assert_eq!(CategoryId::from_oai_set_spec("cs:cs:XX"), None);
+		assert_eq!(CategoryId::from_oai_set_spec("cs:cs:"), None);
+		assert_eq!(CategoryId::from_oai_set_spec("physics:hep-th:TH"), None);
+		assert_eq!(CategoryId::from_oai_set_spec("cs:LG"), None);
+		assert_eq!(CategoryId::from_oai_set_spec("cs"), None);
+	}
+}
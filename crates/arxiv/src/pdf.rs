@@ -0,0 +1,366 @@
+use crate::{LenientStamp, Stamp, StampError};
+use lopdf::Document;
+use lopdf::content::Content;
+use lopdf::{Error as LopdfError, Object};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::Path;
+
+/// An error that can occur when extracting a stamp from a PDF
+#[cfg_attr(docsrs, doc(cfg(feature = "pdf")))]
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum PdfStampError {
+	/// The PDF could not be read or decoded
+	Pdf(LopdfError),
+	/// The PDF has no pages
+	NoPages,
+	/// The first page has no stamp, as is the case for journal versions of articles
+	NoStamp,
+	/// The stamp on the first page could not be parsed
+	InvalidStamp(StampError),
+}
+
+impl Error for PdfStampError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Pdf(e) => Some(e),
+			Self::InvalidStamp(e) => Some(e),
+			Self::NoPages | Self::NoStamp => None,
+		}
+	}
+}
+
+impl Display for PdfStampError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Pdf(e) => write!(f, "Invalid PDF: {e}"),
+			Self::NoPages => f.write_str("The PDF has no pages"),
+			Self::NoStamp => f.write_str("The first page of the PDF has no arXiv stamp"),
+			Self::InvalidStamp(e) => write!(f, "Invalid arXiv stamp: {e}"),
+		}
+	}
+}
+
+impl From<LopdfError> for PdfStampError {
+	fn from(e: LopdfError) -> Self {
+		Self::Pdf(e)
+	}
+}
+
+impl From<StampError> for PdfStampError {
+	fn from(e: StampError) -> Self {
+		Self::InvalidStamp(e)
+	}
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pdf")))]
+impl Stamp<'_> {
+	/// Extracts the stamp from the sidebar of the first page of a PDF.
+	///
+	/// arXiv prints the stamp as text that is rotated by 90 degrees, so only rotated text is
+	/// considered; any other mention of an arXiv identifier on the page is ignored. The text is
+	/// parsed [leniently](Stamp::parse_lenient()), since the stamp may be split into several
+	/// pieces of text.
+	///
+	/// # Errors
+	/// Fails with [`PdfStampError::NoStamp`] if the first page has no rotated text that starts
+	/// with `arXiv:`, which is the case for PDFs that were not generated by arXiv.
+	pub fn from_pdf(bytes: &[u8]) -> Result<LenientStamp, PdfStampError> {
+		extract(&Document::load_mem(bytes)?)
+	}
+
+	/// Extracts the stamp from the sidebar of the first page of a PDF file.
+	///
+	/// See [`Stamp::from_pdf()`] for how the stamp is located.
+	pub fn from_pdf_file(path: impl AsRef<Path>) -> Result<LenientStamp, PdfStampError> {
+		extract(&Document::load(path)?)
+	}
+}
+
+/// The prefix that every stamp starts with
+const STAMP_PREFIX: &str = "arXiv:";
+
+/// The adjustment of a `TJ` array, in thousandths of a unit of text space, beyond which the gap
+/// between two strings is treated as a space
+const TJ_SPACE_THRESHOLD: f32 = -250.0;
+
+fn extract(document: &Document) -> Result<LenientStamp, PdfStampError> {
+	let (_, page_id) = document
+		.get_pages()
+		.into_iter()
+		.next()
+		.ok_or(PdfStampError::NoPages)?;
+	let content = Content::decode(&document.get_page_content(page_id)?)?;
+
+	rotated_text(&content)
+		.iter()
+		.find_map(|text| text.find(STAMP_PREFIX).map(|start| &text[start..]))
+		.ok_or(PdfStampError::NoStamp)
+		.and_then(|text| Ok(LenientStamp::try_from(text)?))
+}
+
+/// Collects the runs of consecutive text that is shown rotated by a quarter turn.
+///
+/// Pieces of a run that are positioned separately (such as words that are each shown in their own
+/// text object) are separated by a space, unless either piece already has one.
+fn rotated_text(content: &Content) -> Vec<String> {
+	let mut runs = Vec::new();
+	let mut run = String::new();
+	let mut ctm = Matrix::IDENTITY;
+	let mut saved = Vec::new();
+	let mut tm = Matrix::IDENTITY;
+	let mut repositioned = false;
+
+	for operation in &content.operations {
+		let operands = &operation.operands;
+		let shown = match operation.operator.as_str() {
+			"q" => {
+				saved.push(ctm);
+				continue;
+			}
+			"Q" => {
+				ctm = saved.pop().unwrap_or(Matrix::IDENTITY);
+				continue;
+			}
+			"cm" => {
+				if let Some(m) = Matrix::from_operands(operands) {
+					ctm = m.then(ctm);
+				}
+				continue;
+			}
+			"BT" => {
+				tm = Matrix::IDENTITY;
+				repositioned = true;
+				continue;
+			}
+			"Tm" => {
+				tm = Matrix::from_operands(operands).unwrap_or(Matrix::IDENTITY);
+				repositioned = true;
+				continue;
+			}
+			"Td" | "TD" | "T*" => {
+				repositioned = true;
+				continue;
+			}
+			"Tj" | "'" | "\"" => operands.last().map(decode).unwrap_or_default(),
+			"TJ" => match operands.first().map(Object::as_array) {
+				Some(Ok(array)) => array
+					.iter()
+					.map(|object| match object.as_float() {
+						Ok(adjustment) if adjustment < TJ_SPACE_THRESHOLD => " ".to_owned(),
+						Ok(_) => String::new(),
+						Err(_) => decode(object),
+					})
+					.collect(),
+				_ => String::new(),
+			},
+			_ => continue,
+		};
+
+		if tm.then(ctm).is_rotated() {
+			let joined = run.is_empty()
+				|| run.ends_with(char::is_whitespace)
+				|| shown.starts_with(char::is_whitespace);
+			if repositioned && !joined {
+				run.push(' ');
+			}
+			run.push_str(&shown);
+		} else if !run.is_empty() {
+			runs.push(std::mem::take(&mut run));
+		}
+		repositioned = false;
+	}
+	if !run.is_empty() {
+		runs.push(run);
+	}
+	runs
+}
+
+/// Decodes the bytes of a string as Latin-1, which covers the standard fonts that stamps use.
+fn decode(object: &Object) -> String {
+	object
+		.as_str()
+		.map(|bytes| bytes.iter().copied().map(char::from).collect())
+		.unwrap_or_default()
+}
+
+/// The linear part of a transformation matrix, which is all that matters for the rotation
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Matrix([f32; 4]);
+
+impl Matrix {
+	const IDENTITY: Self = Self([1.0, 0.0, 0.0, 1.0]);
+
+	fn from_operands(operands: &[Object]) -> Option<Self> {
+		match operands {
+			[a, b, c, d, _, _] => Some(Self([
+				a.as_float().ok()?,
+				b.as_float().ok()?,
+				c.as_float().ok()?,
+				d.as_float().ok()?,
+			])),
+			_ => None,
+		}
+	}
+
+	/// The transformation that applies this one, then the other one.
+	fn then(self, other: Self) -> Self {
+		let [a, b, c, d] = self.0;
+		let [e, f, g, h] = other.0;
+		Self([a * e + b * g, a * f + b * h, c * e + d * g, c * f + d * h])
+	}
+
+	/// Checks if the transformation turns horizontal text into vertical text.
+	fn is_rotated(&self) -> bool {
+		let [a, b, c, d] = self.0;
+		a.abs() < f32::EPSILON && d.abs() < f32::EPSILON && b != 0.0 && c != 0.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{PdfStampError, Stamp, StampNormalization};
+	use lopdf::content::{Content, Operation};
+	use lopdf::{Document, Object, Stream, dictionary};
+
+	/// Creates a single-page PDF that shows the content.
+	fn pdf(operations: Vec<Operation>) -> Vec<u8> {
+		let mut document = Document::with_version("1.5");
+		let pages_id = document.new_object_id();
+		let font_id = document.add_object(dictionary! {
+			"Type" => "Font",
+			"Subtype" => "Type1",
+			"BaseFont" => "Times-Roman",
+		});
+		let resources_id = document.add_object(dictionary! {
+			"Font" => dictionary! { "F1" => font_id },
+		});
+		let content = Content { operations }.encode().unwrap();
+		let content_id = document.add_object(Stream::new(dictionary! {}, content));
+		let page_id = document.add_object(dictionary! {
+			"Type" => "Page",
+			"Parent" => pages_id,
+			"Contents" => content_id,
+		});
+		document.objects.insert(
+			pages_id,
+			Object::Dictionary(dictionary! {
+				"Type" => "Pages",
+				"Kids" => vec![page_id.into()],
+				"Count" => 1,
+				"Resources" => resources_id,
+				"MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+			}),
+		);
+		let catalog_id = document.add_object(dictionary! {
+			"Type" => "Catalog",
+			"Pages" => pages_id,
+		});
+		document.trailer.set("Root", catalog_id);
+
+		let mut bytes = Vec::new();
+		document.save_to(&mut bytes).unwrap();
+		bytes
+	}
+
+	fn text(matrix: [i64; 6], shown: Operation) -> Vec<Operation> {
+		vec![
+			Operation::new("BT", vec![]),
+			Operation::new("Tf", vec!["F1".into(), 20.into()]),
+			Operation::new("Tm", matrix.iter().map(|&n| n.into()).collect()),
+			shown,
+			Operation::new("ET", vec![]),
+		]
+	}
+
+	fn tj(s: &str) -> Operation {
+		Operation::new("Tj", vec![Object::string_literal(s)])
+	}
+
+	const STAMP: &str = "arXiv:2001.00001v1 [cs.LG] 1 Jan 2020";
+
+	#[test]
+	fn rotated_stamp() {
+		let mut operations = text([1, 0, 0, 1, 72, 700], tj("See arXiv:1901.00001 for details"));
+		operations.extend(text([0, 1, -1, 0, 32, 265], tj(STAMP)));
+		let lenient = Stamp::from_pdf(&pdf(operations)).unwrap();
+		assert_eq!(lenient.stamp(), Stamp::try_from(STAMP).unwrap());
+		assert!(lenient.is_canonical());
+	}
+
+	#[test]
+	fn rotated_by_graphics_state() {
+		let mut operations = vec![
+			Operation::new("q", vec![]),
+			Operation::new("cm", [0, -1, 1, 0, 32, 500].map(Object::from).to_vec()),
+		];
+		let kerned = vec![
+			Object::string_literal("arXiv:2001.00001v1"),
+			Object::from(-300),
+			Object::string_literal("[cs.LG]"),
+			Object::from(-20),
+			Object::string_literal("  1 Jan 2020"),
+		];
+		operations.extend(text([1, 0, 0, 1, 0, 0], Operation::new("TJ", vec![kerned.into()])));
+		operations.push(Operation::new("Q", vec![]));
+
+		let lenient = Stamp::from_pdf(&pdf(operations)).unwrap();
+		assert_eq!(lenient.as_str(), STAMP);
+		assert_eq!(lenient.normalizations(), [StampNormalization::CollapsedWhitespace]);
+	}
+
+	#[test]
+	fn stamp_split_into_pieces() {
+		let mut operations = text([0, 1, -1, 0, 32, 265], tj("arXiv:2001.00001v1"));
+		operations.extend(text([0, 1, -1, 0, 32, 400], tj(" [cs.LG]  1 Jan 2020")));
+		let lenient = Stamp::from_pdf(&pdf(operations)).unwrap();
+		assert_eq!(lenient.as_str(), STAMP);
+	}
+
+	#[test]
+	fn stamp_split_into_words() {
+		let mut operations = Vec::new();
+		for (y, word) in [265, 400, 460, 475, 510].into_iter().zip(STAMP.split(' ')) {
+			operations.extend(text([0, 1, -1, 0, 32, y], tj(word)));
+		}
+		let lenient = Stamp::from_pdf(&pdf(operations)).unwrap();
+		assert_eq!(lenient.as_str(), STAMP);
+		assert!(lenient.is_canonical());
+
+		// words positioned within a single text object
+		let mut operations = text([0, 1, -1, 0, 32, 265], tj("arXiv:2001.00001v1"));
+		operations.insert(4, Operation::new("Td", vec![135.into(), 0.into()]));
+		operations.insert(5, tj("[cs.LG]"));
+		operations.insert(6, Operation::new("Td", vec![60.into(), 0.into()]));
+		operations.insert(7, tj("1 Jan 2020"));
+		let lenient = Stamp::from_pdf(&pdf(operations)).unwrap();
+		assert_eq!(lenient.as_str(), STAMP);
+	}
+
+	#[test]
+	fn no_stamp() {
+		let operations = text([1, 0, 0, 1, 72, 700], tj(STAMP));
+		assert!(matches!(Stamp::from_pdf(&pdf(operations)), Err(PdfStampError::NoStamp)));
+
+		let operations = text([0, 1, -1, 0, 32, 265], tj("Preprint submitted to Elsevier"));
+		assert!(matches!(Stamp::from_pdf(&pdf(operations)), Err(PdfStampError::NoStamp)));
+	}
+
+	#[test]
+	fn invalid_stamp() {
+		let operations = text([0, 1, -1, 0, 32, 265], tj("arXiv:2001.00001v1 [cs.XX] 1 Jan 2020"));
+		let err = Stamp::from_pdf(&pdf(operations)).unwrap_err();
+		assert!(matches!(err, PdfStampError::InvalidStamp(_)));
+		assert_eq!(err.to_string(), "Invalid arXiv stamp: Invalid category");
+	}
+
+	#[test]
+	fn invalid_pdf() {
+		assert!(matches!(Stamp::from_pdf(b"not a pdf"), Err(PdfStampError::Pdf(_))));
+		assert!(matches!(
+			Stamp::from_pdf_file("/nonexistent/article.pdf"),
+			Err(PdfStampError::Pdf(_))
+		));
+	}
+}
@@ -0,0 +1,154 @@
+use crate::Stamp;
+use std::ops::Range;
+use std::str::MatchIndices;
+
+/// A stamp that was found in a larger text
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StampMatch<'a> {
+	/// The parsed stamp, which borrows from the text
+	pub stamp: Stamp<'a>,
+	/// The byte range of the stamp within the text
+	pub span: Range<usize>,
+}
+
+/// An iterator over the stamps in a text, in the order they appear
+///
+/// This is created by [`Stamp::find_all()`].
+#[derive(Debug, Clone)]
+pub struct StampMatches<'a> {
+	text: &'a str,
+	prefixes: MatchIndices<'a, &'static str>,
+	resume: usize,
+}
+
+/// The prefix that every stamp starts with
+const STAMP_PREFIX: &str = "arXiv:";
+
+/// The largest number of space-separated components in a stamp: the identifier, the category and
+/// the day, month and year of the date
+const MAX_COMPONENTS: usize = 5;
+
+impl<'a> Iterator for StampMatches<'a> {
+	type Item = StampMatch<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		for (start, _) in self.prefixes.by_ref() {
+			let is_word_start = self.text[..start]
+				.chars()
+				.next_back()
+				.is_none_or(|c| !c.is_alphanumeric());
+			if start < self.resume || !is_word_start {
+				continue;
+			}
+			if let Some((stamp, end)) = parse_at(&self.text[start..]) {
+				self.resume = start + end;
+				return Some(StampMatch {
+					stamp,
+					span: start..start + end,
+				});
+			}
+		}
+		None
+	}
+}
+
+/// Parses the stamp at the start of the text, returning it along with its length.
+///
+/// Stamps either have five components, or four if an old-scheme stamp omits its category. The year
+/// is the last component, and ends after its digits.
+fn parse_at(text: &str) -> Option<(Stamp<'_>, usize)> {
+	let mut starts = vec![0];
+	starts.extend(
+		text.match_indices(' ')
+			.map(|(i, _)| i + 1)
+			.take(MAX_COMPONENTS - 1),
+	);
+
+	(4..=MAX_COMPONENTS).rev().find_map(|components| {
+		let year_start = *starts.get(components - 1)?;
+		let year_len = text[year_start..]
+			.find(|c: char| !c.is_ascii_digit())
+			.unwrap_or(text.len() - year_start);
+		let end = year_start + year_len;
+		Stamp::try_from(&text[..end]).ok().map(|stamp| (stamp, end))
+	})
+}
+
+impl<'a> Stamp<'a> {
+	/// Finds every stamp in a text, such as the extracted text of a thesis or of proceedings.
+	///
+	/// Stamps are matched with the same grammar as [`Stamp::try_from()`], and must start at the
+	/// beginning of a word.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Stamp;
+	///
+	/// let text = "Chapter 2\narXiv:2001.00001v2 [cs.LG] 3 Feb 2020\nIntroduction...\n\
+	///             arXiv:hep-th/9901001v1 7 Jan 1999, see also arXiv:1234.";
+	/// let matches: Vec<_> = Stamp::find_all(text).collect();
+	/// assert_eq!(matches.len(), 2);
+	/// assert_eq!(&text[matches[0].span.clone()], "arXiv:2001.00001v2 [cs.LG] 3 Feb 2020");
+	/// assert_eq!(matches[1].stamp.to_string(), "arXiv:hep-th/9901001v1 7 Jan 1999");
+	/// ```
+	pub fn find_all(text: &'a str) -> StampMatches<'a> {
+		StampMatches {
+			text,
+			prefixes: text.match_indices(STAMP_PREFIX),
+			resume: 0,
+		}
+	}
+
+	/// Finds the first stamp in a text.
+	///
+	/// See [`Stamp::find_all()`] for how stamps are matched.
+	#[must_use]
+	pub fn find(text: &'a str) -> Option<StampMatch<'a>> {
+		Self::find_all(text).next()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{CategoryId, Stamp};
+
+	#[test]
+	fn spans() {
+		let text =
+			"xx arXiv:0706.0001v1 [q-bio.CB] 1 Jun 2007. arXiv:0706.0002 [cs.LG] 12 Jun 2007";
+		let spans: Vec<_> = Stamp::find_all(text).map(|m| m.span).collect();
+		assert_eq!(spans, [3..42, 44..79]);
+		for span in spans {
+			assert!(Stamp::try_from(&text[span]).is_ok());
+		}
+	}
+
+	#[test]
+	fn old_scheme_with_and_without_category() {
+		let text = "arXiv:math/0309136v1 [math.GT] 9 Sep 2003\narXiv:hep-th/9901001v2 3 Feb 1999";
+		let stamps: Vec<_> = Stamp::find_all(text).map(|m| m.stamp).collect();
+		assert_eq!(stamps.len(), 2);
+		assert_eq!(stamps[0].category, CategoryId::try_from("math.GT").ok());
+		assert_eq!(stamps[1].category, CategoryId::try_from("hep-th").ok());
+	}
+
+	#[test]
+	fn skips_non_stamps() {
+		let text = "cite arXiv:2001.00001 and XarXiv:2001.00001 [cs.LG] 1 Jan 2020 or arXiv: \
+		            arXiv:2001.00001 [cs.LG] 1 January 2020 arXiv:2001.00001 [cs.LG]";
+		assert_eq!(Stamp::find(text), None);
+		assert_eq!(Stamp::find_all("").count(), 0);
+
+		let text = "arXiv:1.0001 [cs.LG] 1 Jan 2020 arXiv:1é.0001 [cs.LG] 1 Jan 2020 \
+		            arXiv:hep-th/990100é 3 Feb 1999";
+		assert_eq!(Stamp::find_all(text).count(), 0);
+	}
+
+	#[test]
+	fn adjacent_punctuation() {
+		let text = "(arXiv:2001.00001 [cs.LG] 1 Jan 2020)";
+		let found = Stamp::find(text).unwrap();
+		assert_eq!(found.span, 1..text.len() - 1);
+		assert_eq!(found.stamp, Stamp::try_from(&text[found.span.clone()]).unwrap());
+	}
+}
@@ -0,0 +1,367 @@
+use crate::ARXIV_TIME_ZONE;
+use jiff::civil::{Date, Time, Weekday, time};
+use jiff::{Error as JiffError, ToSpan, Zoned};
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+/// The time of day at which submissions close for the next announcement, in arXiv's time zone
+pub const SUBMISSION_DEADLINE: Time = time(14, 0, 0, 0);
+
+/// The time of day at which new submissions are announced, in arXiv's time zone
+pub const ANNOUNCEMENT_TIME: Time = time(20, 0, 0, 0);
+
+/// A mailing cycle: the submissions received during a window, and the time they are announced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mailing {
+	/// The start of the submission window, which is the deadline of the previous mailing
+	pub opens: Zoned,
+	/// The end of the submission window
+	pub deadline: Zoned,
+	/// The time at which the submissions are announced
+	pub announced: Zoned,
+}
+
+impl Mailing {
+	/// Checks if a submission at the given time belongs to the mailing.
+	#[must_use]
+	pub fn contains(&self, submitted: &Zoned) -> bool {
+		let submitted = submitted.timestamp();
+		self.opens.timestamp() <= submitted && submitted < self.deadline.timestamp()
+	}
+}
+
+/// arXiv's announcement schedule, along with a calendar of holidays
+///
+/// Submissions are received until the [deadline](SUBMISSION_DEADLINE) on weekdays, and are
+/// announced at [8 PM](ANNOUNCEMENT_TIME) on the same day from Monday to Thursday, while the
+/// submissions of Friday are announced on Sunday. There is no announcement on holidays, so the
+/// submissions that would have been announced on a holiday roll over into the next mailing.
+///
+/// All times are in arXiv's time zone ([`ARXIV_TIME_ZONE`]).
+///
+/// # Examples
+/// ```
+/// use arxiv::AnnouncementSchedule;
+/// use jiff::civil::date;
+///
+/// let schedule = AnnouncementSchedule::new();
+/// let submitted = date(2024, 11, 27).at(15, 30, 0, 0).in_tz("America/New_York").unwrap();
+/// let announced = schedule.announcement(&submitted).unwrap();
+/// assert_eq!(announced.datetime(), date(2024, 11, 28).at(20, 0, 0, 0));
+///
+/// // no announcement on Thanksgiving
+/// let schedule = AnnouncementSchedule::with_holidays([date(2024, 11, 28)]);
+/// let announced = schedule.announcement(&submitted).unwrap();
+/// assert_eq!(announced.datetime(), date(2024, 12, 1).at(20, 0, 0, 0));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnnouncementSchedule {
+	holidays: BTreeSet<Date>,
+}
+
+impl AnnouncementSchedule {
+	/// Creates the schedule without any holidays.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates the schedule with the given dates on which there are no announcements.
+	#[must_use]
+	pub fn with_holidays(holidays: impl IntoIterator<Item = Date>) -> Self {
+		Self {
+			holidays: holidays.into_iter().collect(),
+		}
+	}
+
+	/// Adds a date on which there is no announcement.
+	///
+	/// Returns whether the date was added, which is not the case if it already was a holiday.
+	pub fn add_holiday(&mut self, date: Date) -> bool {
+		self.holidays.insert(date)
+	}
+
+	/// Checks if there is no announcement on the date because of a holiday.
+	#[must_use]
+	pub fn is_holiday(&self, date: Date) -> bool {
+		self.holidays.contains(&date)
+	}
+
+	/// An iterator over the holidays, in chronological order
+	pub fn holidays(&self) -> impl Iterator<Item = Date> + '_ {
+		self.holidays.iter().copied()
+	}
+
+	/// The time at which a submission will be announced
+	///
+	/// # Errors
+	/// Fails if the time zone database is unavailable, or if the announcement would be beyond
+	/// the supported range of dates.
+	pub fn announcement(&self, submitted: &Zoned) -> Result<Zoned, JiffError> {
+		Ok(self.mailing(submitted)?.announced)
+	}
+
+	/// The mailing that a submission belongs to
+	///
+	/// # Errors
+	/// Fails if the time zone database is unavailable, or if the mailing would be beyond the
+	/// supported range of dates.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::AnnouncementSchedule;
+	/// use jiff::civil::date;
+	///
+	/// // submitted on a Saturday
+	/// let submitted = date(2024, 10, 5).at(9, 0, 0, 0).in_tz("Europe/Berlin").unwrap();
+	/// let mailing = AnnouncementSchedule::new().mailing(&submitted).unwrap();
+	/// assert_eq!(mailing.opens.datetime(), date(2024, 10, 4).at(14, 0, 0, 0));
+	/// assert_eq!(mailing.deadline.datetime(), date(2024, 10, 7).at(14, 0, 0, 0));
+	/// assert_eq!(mailing.announced.datetime(), date(2024, 10, 7).at(20, 0, 0, 0));
+	/// assert!(mailing.contains(&submitted));
+	/// ```
+	pub fn mailing(&self, submitted: &Zoned) -> Result<Mailing, JiffError> {
+		let local = submitted.in_tz(ARXIV_TIME_ZONE)?;
+		let mut day = local.date();
+		if local.time() >= SUBMISSION_DEADLINE {
+			day = day.tomorrow()?;
+		}
+		let (deadline, announced) = loop {
+			if let Some(announced) = self.announcement_date(day) {
+				break (day, announced);
+			}
+			day = day.tomorrow()?;
+		};
+		self.mailing_at(deadline, announced)
+	}
+
+	/// The mailings that are announced from the first date up to and including the last date
+	///
+	/// # Errors
+	/// Fails if the time zone database is unavailable, or if a mailing would be beyond the
+	/// supported range of dates.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::AnnouncementSchedule;
+	/// use jiff::civil::date;
+	///
+	/// let schedule = AnnouncementSchedule::with_holidays([date(2024, 12, 25)]);
+	/// let mailings = schedule.mailings(date(2024, 12, 22), date(2024, 12, 28)).unwrap();
+	/// let announced: Vec<_> = mailings.iter().map(|m| m.announced.date()).collect();
+	/// assert_eq!(
+	///     announced,
+	///     [date(2024, 12, 22), date(2024, 12, 23), date(2024, 12, 24), date(2024, 12, 26)]
+	/// );
+	/// assert_eq!(mailings[3].opens.date(), date(2024, 12, 24));
+	/// ```
+	pub fn mailings(&self, first: Date, last: Date) -> Result<Vec<Mailing>, JiffError> {
+		let mut mailings = Vec::new();
+		for day in first.series(1.day()).take_while(|day| *day <= last) {
+			if let Some(deadline) = self.deadline_date(day) {
+				mailings.push(self.mailing_at(deadline, day)?);
+			}
+		}
+		Ok(mailings)
+	}
+
+	/// The year and month in the identifier that a submission will receive
+	///
+	/// The identifier is assigned when the submission is announced, so submissions from the last
+	/// days of a month may receive an identifier of the next month.
+	///
+	/// # Errors
+	/// Fails if the time zone database is unavailable, or if the announcement would be beyond
+	/// the supported range of dates.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::AnnouncementSchedule;
+	/// use jiff::civil::date;
+	///
+	/// let submitted = date(2024, 1, 31).at(15, 0, 0, 0).in_tz("America/New_York").unwrap();
+	/// assert_eq!(AnnouncementSchedule::new().id_month(&submitted).unwrap(), (2024, 2));
+	/// ```
+	pub fn id_month(&self, submitted: &Zoned) -> Result<(i16, i8), JiffError> {
+		let announced = self.announcement(submitted)?;
+		Ok((announced.year(), announced.month()))
+	}
+
+	/// The window of submission times that receive identifiers of the given year and month
+	///
+	/// The window starts at the deadline before the first announcement of the month, and ends at
+	/// the deadline before the first announcement of the next month.
+	///
+	/// # Errors
+	/// Fails if the month is invalid or beyond the supported range of dates, or if the time zone
+	/// database is unavailable.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{AnnouncementSchedule, ArticleId};
+	/// use jiff::civil::date;
+	///
+	/// let id = ArticleId::try_from("arXiv:2402.00001").unwrap();
+	/// let window = AnnouncementSchedule::new().id_month_window(id.year(), id.month()).unwrap();
+	/// assert_eq!(window.start.datetime(), date(2024, 1, 31).at(14, 0, 0, 0));
+	/// assert_eq!(window.end.datetime(), date(2024, 2, 29).at(14, 0, 0, 0));
+	/// ```
+	pub fn id_month_window(&self, year: i16, month: i8) -> Result<Range<Zoned>, JiffError> {
+		let first = Date::new(year, month, 1)?;
+		let next = first.last_of_month().tomorrow()?;
+		Ok(self.first_mailing_from(first)?.opens..self.first_mailing_from(next)?.opens)
+	}
+
+	/// The first mailing that is announced on or after the date
+	fn first_mailing_from(&self, mut day: Date) -> Result<Mailing, JiffError> {
+		loop {
+			if let Some(deadline) = self.deadline_date(day) {
+				return self.mailing_at(deadline, day);
+			}
+			day = day.tomorrow()?;
+		}
+	}
+
+	/// The date of the deadline whose submissions are announced on the date, if there is an
+	/// announcement on the date
+	fn deadline_date(&self, announced: Date) -> Option<Date> {
+		if self.is_holiday(announced) {
+			return None;
+		}
+		match announced.weekday() {
+			Weekday::Sunday => announced.checked_sub(2.days()).ok(),
+			Weekday::Friday | Weekday::Saturday => None,
+			_ => Some(announced),
+		}
+	}
+
+	/// The date on which the submissions of a deadline are announced, if there is a deadline on
+	/// the date
+	fn announcement_date(&self, deadline: Date) -> Option<Date> {
+		let announced = match deadline.weekday() {
+			Weekday::Saturday | Weekday::Sunday => return None,
+			Weekday::Friday => deadline.checked_add(2.days()).ok()?,
+			_ => deadline,
+		};
+		(!self.is_holiday(announced)).then_some(announced)
+	}
+
+	fn mailing_at(&self, deadline: Date, announced: Date) -> Result<Mailing, JiffError> {
+		let mut opens = deadline.yesterday()?;
+		while self.announcement_date(opens).is_none() {
+			opens = opens.yesterday()?;
+		}
+		let at = |date: Date, time: Time| date.to_datetime(time).in_tz(ARXIV_TIME_ZONE);
+		Ok(Mailing {
+			opens: at(opens, SUBMISSION_DEADLINE)?,
+			deadline: at(deadline, SUBMISSION_DEADLINE)?,
+			announced: at(announced, ANNOUNCEMENT_TIME)?,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::AnnouncementSchedule;
+	use jiff::Zoned;
+	use jiff::civil::{Date, DateTime, date};
+
+	fn eastern(datetime: DateTime) -> Zoned {
+		datetime.in_tz("America/New_York").unwrap()
+	}
+
+	fn announced(schedule: &AnnouncementSchedule, submitted: DateTime) -> DateTime {
+		schedule
+			.announcement(&eastern(submitted))
+			.unwrap()
+			.datetime()
+	}
+
+	#[test]
+	fn weekdays() {
+		let schedule = AnnouncementSchedule::new();
+		// Monday, 7 October 2024
+		let monday = |day: i8, hour: i8| date(2024, 10, 7 + day).at(hour, 0, 0, 0);
+		assert_eq!(announced(&schedule, monday(0, 13)), monday(0, 20));
+		assert_eq!(announced(&schedule, monday(0, 14)), monday(1, 20));
+		assert_eq!(announced(&schedule, monday(3, 13)), monday(3, 20));
+		assert_eq!(announced(&schedule, monday(3, 15)), monday(6, 20));
+		assert_eq!(announced(&schedule, monday(4, 13)), monday(6, 20));
+		assert_eq!(announced(&schedule, monday(4, 15)), monday(7, 20));
+		assert_eq!(announced(&schedule, monday(6, 21)), monday(7, 20));
+	}
+
+	#[test]
+	fn other_time_zones() {
+		let schedule = AnnouncementSchedule::new();
+		let submitted = date(2024, 10, 7).at(19, 30, 0, 0).in_tz("UTC").unwrap();
+		let announced = schedule.announcement(&submitted).unwrap();
+		assert_eq!(announced.datetime(), date(2024, 10, 8).at(20, 0, 0, 0));
+		assert_eq!(announced.time_zone().iana_name(), Some("America/New_York"));
+	}
+
+	#[test]
+	fn consecutive_holidays() {
+		let holidays: Vec<Date> = (24..=26).map(|day| date(2024, 12, day)).collect();
+		let mut schedule = AnnouncementSchedule::with_holidays(holidays);
+		assert!(!schedule.add_holiday(date(2024, 12, 25)));
+		assert_eq!(schedule.holidays().count(), 3);
+
+		let submitted = date(2024, 12, 23).at(15, 0, 0, 0);
+		assert_eq!(announced(&schedule, submitted), date(2024, 12, 29).at(20, 0, 0, 0));
+
+		let mailing = schedule.mailing(&eastern(submitted)).unwrap();
+		assert_eq!(mailing.opens.datetime(), date(2024, 12, 23).at(14, 0, 0, 0));
+		assert_eq!(mailing.deadline.datetime(), date(2024, 12, 27).at(14, 0, 0, 0));
+	}
+
+	#[test]
+	fn mailings_are_contiguous() {
+		let schedule = AnnouncementSchedule::with_holidays([date(2025, 1, 1)]);
+		let mailings = schedule
+			.mailings(date(2024, 12, 1), date(2025, 1, 31))
+			.unwrap();
+		for pair in mailings.windows(2) {
+			assert_eq!(pair[0].deadline, pair[1].opens);
+			assert!(pair[0].announced < pair[1].announced);
+		}
+		assert!(
+			mailings
+				.iter()
+				.all(|m| m.announced.date() != date(2025, 1, 1))
+		);
+		assert_eq!(
+			schedule
+				.mailings(date(2025, 1, 3), date(2025, 1, 4))
+				.unwrap(),
+			[]
+		);
+	}
+
+	#[test]
+	fn mailing_contains_its_submissions() {
+		let schedule = AnnouncementSchedule::new();
+		let mailing = schedule
+			.mailing(&eastern(date(2024, 10, 9).at(10, 0, 0, 0)))
+			.unwrap();
+		assert!(mailing.contains(&mailing.opens));
+		assert!(!mailing.contains(&mailing.deadline));
+		assert_eq!(schedule.mailing(&mailing.opens).unwrap(), mailing);
+	}
+
+	#[test]
+	fn id_month_boundaries() {
+		let schedule = AnnouncementSchedule::with_holidays([date(2025, 1, 1)]);
+		// submitted after the last deadline of 2024, which falls on Tuesday 31 December
+		let submitted = eastern(date(2024, 12, 31).at(14, 0, 0, 0));
+		assert_eq!(schedule.id_month(&submitted).unwrap(), (2025, 1));
+		let submitted = eastern(date(2024, 12, 31).at(13, 59, 0, 0));
+		assert_eq!(schedule.id_month(&submitted).unwrap(), (2024, 12));
+
+		let window = schedule.id_month_window(2025, 1).unwrap();
+		assert_eq!(window.start.datetime(), date(2024, 12, 31).at(14, 0, 0, 0));
+		assert_eq!(schedule.id_month(&window.start).unwrap(), (2025, 1));
+		assert_eq!(schedule.id_month(&window.end).unwrap(), (2025, 2));
+		assert!(schedule.id_month_window(2025, 13).is_err());
+	}
+}
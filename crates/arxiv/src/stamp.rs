@@ -1,10 +1,11 @@
-use crate::{ArticleId, ArticleIdError, CategoryId};
+use crate::{ArticleId, ArticleIdError, ArticleIdScheme, ArticleVersion, CategoryId};
 use jiff::Error as JiffError;
+use jiff::ToSpan;
 use jiff::civil::Date;
 use jiff::fmt::strtime::format as jiff_format;
 use jiff::fmt::strtime::parse as jiff_parse;
 use std::error::Error;
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 
 /// Convenient type alias for a [`Result`] holding either a [`Stamp`] or [`StampError`]
 pub type StampResult<'a> = Result<Stamp<'a>, StampError>;
@@ -24,6 +25,8 @@ pub enum StampError {
 	InvalidDate,
 	InvalidCategory,
 	NotEnoughComponents,
+	InconsistentDate,
+	InactiveCategory,
 }
 
 impl Error for StampError {}
@@ -35,15 +38,39 @@ impl Display for StampError {
 			Self::InvalidDate => f.write_str("Invalid date"),
 			Self::InvalidCategory => f.write_str("Invalid category"),
 			Self::NotEnoughComponents => f.write_str("Not enough components"),
+			Self::InconsistentDate => {
+				f.write_str("The submission date does not match the month of the arXiv ID")
+			}
+			Self::InactiveCategory => {
+				f.write_str("The category did not accept submissions on the submission date")
+			}
 		}
 	}
 }
 
 /// A stamp that is added onto the side of PDF version of arXiv articles
+///
+/// Stamps of articles with [old-scheme](crate::ArticleIdScheme::Old) identifiers may omit the
+/// bracketed category, since the archive is already part of the identifier:
+/// ```
+/// use arxiv::{CategoryId, Stamp};
+///
+/// let stamp = Stamp::try_from("arXiv:hep-th/9901001v2 3 Feb 1999").unwrap();
+/// assert_eq!(stamp.category, CategoryId::try_from("hep-th").ok());
+/// assert_eq!(stamp.to_string(), "arXiv:hep-th/9901001v2 3 Feb 1999");
+///
+/// // the subject class cannot be inferred from the archive alone
+/// let stamp = Stamp::try_from("arXiv:math/0309136v1 9 Sep 2003").unwrap();
+/// assert_eq!(stamp.category, None);
+///
+/// let stamp = Stamp::try_from("arXiv:math.GT/0309136v1 9 Sep 2003").unwrap();
+/// assert_eq!(stamp.category, CategoryId::try_from("math.GT").ok());
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Stamp<'a> {
 	pub id: ArticleId<'a>,
-	pub category: CategoryId<'a>,
+	/// The primary category of the article, if it is known
+	pub category: Option<CategoryId>,
 	pub submitted: Date,
 }
 
@@ -62,24 +89,154 @@ impl<'a> Stamp<'a> {
 	/// );
 	/// ```
 	#[inline]
-	pub const fn new(id: ArticleId<'a>, category: CategoryId<'a>, submitted: Date) -> Self {
+	pub const fn new(id: ArticleId<'a>, category: CategoryId, submitted: Date) -> Self {
 		Self {
 			id,
-			category,
+			category: Some(category),
 			submitted,
 		}
 	}
+
+	/// Manually create a new [`Stamp`] without a bracketed category, which is inferred from the
+	/// subject class of an old-scheme identifier, or from its archive if the archive has no
+	/// subject classes.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Archive, ArticleId, ArticleVersion, CategoryId, Stamp};
+	/// use jiff::civil::date;
+	///
+	/// let id = ArticleId::new_old(Archive::HepTh, 1999, 1, "001", ArticleVersion::Num(2));
+	/// let stamp = Stamp::without_category(id, date(1999, 2, 3));
+	/// assert_eq!(stamp.category, CategoryId::try_new(Archive::HepTh, ""));
+	/// ```
+	#[must_use]
+	pub fn without_category(id: ArticleId<'a>, submitted: Date) -> Self {
+		Self {
+			id,
+			category: inferred_category(id),
+			submitted,
+		}
+	}
+
+	/// Checks if the category of the stamp accepted new submissions on the date it was submitted.
+	///
+	/// Stamps without a known category fall back to checking the archive of their old-scheme
+	/// identifier.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Stamp;
+	///
+	/// let stamp = Stamp::try_from("arXiv:1709.00001 [econ.EM] 1 Sep 2017").unwrap();
+	/// assert!(stamp.has_valid_category());
+	///
+	/// let stamp = Stamp::try_from("arXiv:1601.00001 [econ.EM] 1 Jan 2016").unwrap();
+	/// assert!(!stamp.has_valid_category());
+	/// ```
+	#[must_use]
+	pub fn has_valid_category(&self) -> bool {
+		match (self.category, self.id.archive()) {
+			(Some(category), _) => category.valid_at(self.submitted),
+			(None, Some(archive)) => archive.valid_at(self.submitted),
+			(None, None) => false,
+		}
+	}
+}
+
+impl<'a> Stamp<'a> {
+	/// Parses a stamp and checks that it is [consistent](Self::validate()).
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Stamp, StampError};
+	///
+	/// assert!(Stamp::parse_strict("arXiv:2001.00001 [cs.LG] 1 Jan 2020").is_ok());
+	///
+	/// let stamp = Stamp::parse_strict("arXiv:2001.00001 [cs.LG] 1 Jan 2000");
+	/// assert_eq!(stamp, Err(StampError::InconsistentDate));
+	/// ```
+	pub fn parse_strict(s: &'a str) -> StampResult<'a> {
+		let stamp = Self::try_from(s)?;
+		stamp.validate()?;
+		Ok(stamp)
+	}
+
+	/// Checks that the submission date is consistent with the identifier, and that the category
+	/// accepted new submissions on that date.
+	///
+	/// The identifier is assigned when an article is announced, so the first version may have
+	/// been submitted during the last days of the month before the one in its identifier. Later
+	/// versions may be submitted at any time after that, so only the first version is checked for
+	/// having been submitted before the end of the month. Identifiers without a version are
+	/// assumed to possibly refer to a later version.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Stamp, StampError};
+	///
+	/// // submitted after the last announcement deadline of December
+	/// let stamp = Stamp::try_from("arXiv:2001.00001v1 [cs.LG] 30 Dec 2019").unwrap();
+	/// assert_eq!(stamp.validate(), Ok(()));
+	///
+	/// let stamp = Stamp::try_from("arXiv:2001.00001v1 [cs.LG] 3 Feb 2020").unwrap();
+	/// assert_eq!(stamp.validate(), Err(StampError::InconsistentDate));
+	///
+	/// let stamp = Stamp::try_from("arXiv:2001.00001v2 [cs.LG] 3 Feb 2020").unwrap();
+	/// assert_eq!(stamp.validate(), Ok(()));
+	///
+	/// let stamp = Stamp::try_from("arXiv:1601.00001 [econ.EM] 1 Jan 2016").unwrap();
+	/// assert_eq!(stamp.validate(), Err(StampError::InactiveCategory));
+	/// ```
+	pub fn validate(&self) -> Result<(), StampError> {
+		let month = Date::new(self.id.year(), self.id.month(), 1)
+			.map_err(|_| StampError::InconsistentDate)?;
+		let earliest = month.saturating_sub(MONTH_BOUNDARY_DAYS.days());
+		let latest = match self.id.version() {
+			ArticleVersion::Num(1) => month.last_of_month(),
+			_ => Date::MAX,
+		};
+		if !(earliest..=latest).contains(&self.submitted) {
+			return Err(StampError::InconsistentDate);
+		}
+		if !self.has_valid_category() {
+			return Err(StampError::InactiveCategory);
+		}
+		Ok(())
+	}
+}
+
+/// The number of days before the start of a month during which articles may be submitted that are
+/// announced with an identifier of that month
+const MONTH_BOUNDARY_DAYS: i64 = 7;
+
+/// The category implied by an old-scheme identifier, which is its subject class if it names one,
+/// or otherwise its archive if the archive has no subject classes
+fn inferred_category(id: ArticleId<'_>) -> Option<CategoryId> {
+	match (id.subject(), id.archive()) {
+		(Some(subject), _) => Some(CategoryId::from(subject)),
+		(None, Some(archive)) if !archive.contains_subjects() => CategoryId::from_archive(archive),
+		_ => None,
+	}
+}
+
+impl Stamp<'_> {
+	/// Writes the stamp with a submission date that was already formatted.
+	pub(crate) fn write_with_date(&self, f: &mut impl Write, submitted: &str) -> FmtResult {
+		// the category is omitted when the identifier already implies it, as on old-scheme stamps
+		match self.category {
+			Some(category) if self.category != inferred_category(self.id) => {
+				write!(f, "{} [{category}] {submitted}", self.id)
+			}
+			_ => write!(f, "{} {submitted}", self.id),
+		}
+	}
 }
 
 impl Display for Stamp<'_> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-		write!(
-			f,
-			"{} [{}] {}",
-			self.id,
-			self.category,
-			jiff_format("%-e %b %Y", self.submitted).map_err(|_| core::fmt::Error)?
-		)
+		let submitted = jiff_format("%-e %b %Y", self.submitted).map_err(|_| core::fmt::Error)?;
+		self.write_with_date(f, &submitted)
 	}
 }
 
@@ -98,10 +255,20 @@ impl<'a> TryFrom<&'a str> for Stamp<'a> {
 		let space1 = wsp_indices[0].0;
 		let id = ArticleId::try_from(&s[0..space1]).map_err(InvalidArxivId)?;
 
-		// parse a category
+		// parse a category, which old-scheme stamps may omit
 		let space2 = wsp_indices[1].0;
 		let cat_str = &s[space1 + 1..space2];
+		if !cat_str.starts_with('[') && id.scheme() == ArticleIdScheme::Old {
+			let date = parse_date(&s[space1 + 1..]).map_err(|_| InvalidDate)?;
+			return Ok(Self::without_category(id, date));
+		}
 		let category = CategoryId::parse_bracketed(cat_str).ok_or(InvalidCategory)?;
+		if id
+			.archive()
+			.is_some_and(|archive| archive != category.archive())
+		{
+			return Err(InvalidCategory);
+		}
 
 		// parse a date
 		let date_str = &s[space2 + 1..];
@@ -168,12 +335,133 @@ mod tests_parse_ok {
 			))
 		)
 	}
+
+	#[test]
+	fn parse_stamp_archive_without_subject() {
+		let stamp = Stamp::try_from("arXiv:0801.0001v1 [astro-ph] 1 Jan 2008").unwrap();
+		assert_eq!(stamp.category, CategoryId::from_archive(Archive::AstroPh));
+		assert_eq!(stamp.to_string(), "arXiv:0801.0001v1 [astro-ph] 1 Jan 2008");
+	}
 }
 
 #[cfg(test)]
-mod tests_parse_err {
+mod tests_parse_old {
+	use crate::{Archive, ArticleId, ArticleVersion, CategoryId, Stamp, StampError};
+	use jiff::civil::date;
+
+	#[test]
+	fn without_category() {
+		let parsed = Stamp::try_from("arXiv:hep-th/9901001v2 3 Feb 1999");
+		let id = ArticleId::new_old(Archive::HepTh, 1999, 1, "001", ArticleVersion::Num(2));
+		let stamp = Stamp {
+			id,
+			category: CategoryId::try_new(Archive::HepTh, ""),
+			submitted: date(1999, 2, 3),
+		};
+		assert_eq!(parsed, Ok(stamp));
+		assert!(parsed.as_ref().is_ok_and(Stamp::has_valid_category));
+
+		let bracketed = Stamp::try_from("arXiv:hep-th/9901001v2 [hep-th] 3 Feb 1999");
+		assert_eq!(bracketed, parsed);
+	}
+
+	#[test]
+	fn legacy_archive() {
+		let stamp = Stamp::try_from("arXiv:chao-dyn/9501003v1 10 Jan 1995").unwrap();
+		assert_eq!(stamp.category, CategoryId::try_new(Archive::ChaoDyn, ""));
+		assert!(stamp.has_valid_category());
+	}
+
+	#[test]
+	fn archive_with_subjects() {
+		let stamp = Stamp::try_from("arXiv:cond-mat/0501001 1 Jan 2005").unwrap();
+		assert_eq!(stamp.category, None);
+		assert!(stamp.has_valid_category());
+		assert_eq!(stamp.to_string(), "arXiv:cond-mat/0501001 1 Jan 2005");
+	}
+
+	#[test]
+	fn with_category() {
+		let stamp = Stamp::try_from("arXiv:math/0309136v1 [math.GT] 9 Sep 2003").unwrap();
+		assert_eq!(stamp.category, CategoryId::try_new(Archive::Math, "GT"));
+		assert_eq!(stamp.to_string(), "arXiv:math/0309136v1 [math.GT] 9 Sep 2003");
+	}
+
+	#[test]
+	fn category_from_subject_class() {
+		let stamp = Stamp::try_from("arXiv:math.GT/0309136v1 9 Sep 2003").unwrap();
+		assert_eq!(stamp.category, CategoryId::try_new(Archive::Math, "GT"));
+		assert!(stamp.has_valid_category());
+		assert_eq!(stamp.to_string(), "arXiv:math.GT/0309136v1 9 Sep 2003");
+	}
+
+	#[test]
+	fn category_of_other_archive() {
+		let parsed = Stamp::try_from("arXiv:math/0309136v1 [cs.LG] 9 Sep 2003");
+		assert_eq!(parsed, Err(StampError::InvalidCategory));
+	}
+
+	#[test]
+	fn new_scheme_requires_category() {
+		let parsed = Stamp::try_from("arXiv:0706.0001v1 1 Jun 2007");
+		assert_eq!(parsed, Err(StampError::InvalidCategory));
+	}
+}
+
+#[cfg(test)]
+mod tests_validate {
 	use crate::{Stamp, StampError};
 
+	fn validate(s: &str) -> Result<(), StampError> {
+		Stamp::try_from(s).unwrap().validate()
+	}
+
+	#[test]
+	fn consistent() {
+		assert_eq!(validate("arXiv:2001.00001v1 [cs.LG] 1 Jan 2020"), Ok(()));
+		assert_eq!(validate("arXiv:2001.00001v1 [cs.LG] 31 Jan 2020"), Ok(()));
+		assert_eq!(validate("arXiv:2001.00001v1 [cs.LG] 25 Dec 2019"), Ok(()));
+		assert_eq!(validate("arXiv:2001.00001v3 [cs.LG] 5 May 2023"), Ok(()));
+		assert_eq!(validate("arXiv:2001.00001 [cs.LG] 5 May 2023"), Ok(()));
+		assert_eq!(validate("arXiv:hep-th/9901001v2 3 Feb 1999"), Ok(()));
+	}
+
+	#[test]
+	fn inconsistent_date() {
+		use StampError::InconsistentDate;
+
+		assert_eq!(validate("arXiv:2001.00001 [cs.LG] 1 Jan 2000"), Err(InconsistentDate));
+		assert_eq!(validate("arXiv:2001.00001v1 [cs.LG] 24 Dec 2019"), Err(InconsistentDate));
+		assert_eq!(validate("arXiv:2001.00001v1 [cs.LG] 1 Feb 2020"), Err(InconsistentDate));
+		assert_eq!(validate("arXiv:2001.00001v2 [cs.LG] 1 Jan 2019"), Err(InconsistentDate));
+		assert_eq!(validate("arXiv:hep-th/9901001v1 3 Feb 1999"), Err(InconsistentDate));
+	}
+
+	#[test]
+	fn inactive_category() {
+		use StampError::InactiveCategory;
+
+		assert_eq!(validate("arXiv:1601.00001v1 [econ.EM] 1 Jan 2016"), Err(InactiveCategory));
+		assert_eq!(validate("arXiv:2305.00001v1 [chao-dyn] 1 May 2023"), Err(InactiveCategory));
+	}
+
+	#[test]
+	fn strict_parse() {
+		assert_eq!(
+			Stamp::parse_strict("arXiv:2001.00001 [cs.LG] 1 Jan 2000"),
+			Err(StampError::InconsistentDate)
+		);
+		assert_eq!(
+			Stamp::parse_strict("arXiv:2001.00001 [cs.LG] 1 Jan"),
+			Err(StampError::InvalidDate)
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests_parse_err {
+	use crate::{ArticleIdError, Stamp, StampError};
+
 	#[test]
 	fn is_empty() {
 		let stamp = "";
@@ -190,6 +478,14 @@ mod tests_parse_err {
 		assert_eq!(parsed, Err(StampError::NotEnoughComponents));
 	}
 
+	#[test]
+	fn non_ascii_old_scheme_id() {
+		let stamp = "arXiv:hep-th/990100é 3 Feb 1999";
+		let parsed = Stamp::try_from(stamp);
+
+		assert_eq!(parsed, Err(StampError::InvalidArxivId(ArticleIdError::ExpectedNumberVv)));
+	}
+
 	#[test]
 	fn invalid_category() {
 		let stamp = "arXiv:2001.00001 [cs.LG 1 Jan 2000";
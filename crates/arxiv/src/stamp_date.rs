@@ -0,0 +1,234 @@
+use crate::Stamp;
+use jiff::fmt::rfc2822;
+use jiff::fmt::strtime::format as jiff_format;
+use jiff::{Error as JiffError, Timestamp, Zoned};
+use std::ops::Range;
+
+/// The time zone in which arXiv dates its submissions and announcements
+pub const ARXIV_TIME_ZONE: &str = "America/New_York";
+
+/// A format for the submission date of a stamp
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum StampDateFormat {
+	/// The format printed on stamps, like `1 Jan 2020`
+	#[default]
+	Arxiv,
+	/// An ISO 8601 calendar date, like `2020-01-01`
+	Iso8601,
+	/// An RFC 2822 date-time at the start of the day in arXiv's time zone, like
+	/// `Wed, 1 Jan 2020 00:00:00 -0500`
+	Rfc2822,
+}
+
+impl Stamp<'_> {
+	/// The time span of the submission day, from its start until the start of the next day in
+	/// arXiv's time zone ([`ARXIV_TIME_ZONE`])
+	///
+	/// # Errors
+	/// Fails if the time zone database is unavailable, or if the day is at the edge of the
+	/// supported range of dates.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Stamp;
+	///
+	/// let stamp = Stamp::try_from("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// let day = stamp.submitted_range().unwrap();
+	/// assert_eq!(day.start.to_string(), "2020-01-01T00:00:00-05:00[America/New_York]");
+	/// assert_eq!(day.end.to_string(), "2020-01-02T00:00:00-05:00[America/New_York]");
+	/// ```
+	pub fn submitted_range(&self) -> Result<Range<Zoned>, JiffError> {
+		let start = self.submitted.in_tz(ARXIV_TIME_ZONE)?;
+		let end = self.submitted.tomorrow()?.in_tz(ARXIV_TIME_ZONE)?;
+		Ok(start..end)
+	}
+
+	/// The time span of the submission day as UTC timestamps
+	///
+	/// See [`Stamp::submitted_range()`] for how the day is delimited.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Stamp;
+	///
+	/// let stamp = Stamp::try_from("arXiv:2007.00001 [cs.LG] 1 Jul 2020").unwrap();
+	/// let day = stamp.submitted_timestamps().unwrap();
+	/// assert_eq!(day.start.to_string(), "2020-07-01T04:00:00Z");
+	/// assert_eq!(day.end.to_string(), "2020-07-02T04:00:00Z");
+	/// ```
+	pub fn submitted_timestamps(&self) -> Result<Range<Timestamp>, JiffError> {
+		let Range { start, end } = self.submitted_range()?;
+		Ok(start.timestamp()..end.timestamp())
+	}
+
+	/// Formats the submission date.
+	///
+	/// # Errors
+	/// Fails if the time zone database is unavailable for [`StampDateFormat::Rfc2822`].
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Stamp, StampDateFormat};
+	///
+	/// let stamp = Stamp::try_from("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// assert_eq!(stamp.format_date(StampDateFormat::Arxiv).unwrap(), "1 Jan 2020");
+	/// assert_eq!(stamp.format_date(StampDateFormat::Iso8601).unwrap(), "2020-01-01");
+	/// assert_eq!(
+	///     stamp.format_date(StampDateFormat::Rfc2822).unwrap(),
+	///     "Wed, 1 Jan 2020 00:00:00 -0500"
+	/// );
+	/// ```
+	pub fn format_date(&self, format: StampDateFormat) -> Result<String, JiffError> {
+		match format {
+			StampDateFormat::Arxiv => jiff_format("%-e %b %Y", self.submitted),
+			StampDateFormat::Iso8601 => Ok(self.submitted.to_string()),
+			StampDateFormat::Rfc2822 => rfc2822::to_string(&self.submitted.in_tz(ARXIV_TIME_ZONE)?),
+		}
+	}
+
+	/// Formats the stamp with the submission date in another format.
+	///
+	/// # Errors
+	/// Fails if the date cannot be formatted, see [`Stamp::format_date()`].
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Stamp, StampDateFormat};
+	///
+	/// let stamp = Stamp::try_from("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// let s = stamp.to_string_with(StampDateFormat::Iso8601).unwrap();
+	/// assert_eq!(s, "arXiv:2001.00001 [cs.LG] 2020-01-01");
+	/// ```
+	pub fn to_string_with(&self, format: StampDateFormat) -> Result<String, JiffError> {
+		let submitted = self.format_date(format)?;
+		let mut s = String::new();
+		self.write_with_date(&mut s, &submitted)
+			.expect("writing to a string cannot fail");
+		Ok(s)
+	}
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl Stamp<'_> {
+	/// The submission date as a [`chrono::NaiveDate`]
+	///
+	/// ```
+	/// use arxiv::Stamp;
+	///
+	/// let stamp = Stamp::try_from("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// assert_eq!(stamp.submitted_chrono().to_string(), "2020-01-01");
+	/// ```
+	#[must_use]
+	pub fn submitted_chrono(&self) -> chrono::NaiveDate {
+		let date = self.submitted;
+		chrono::NaiveDate::from_ymd_opt(
+			i32::from(date.year()),
+			date.month().unsigned_abs().into(),
+			date.day().unsigned_abs().into(),
+		)
+		.expect("every jiff date is a valid chrono date")
+	}
+
+	/// The time span of the submission day as [`chrono::DateTime`]s in UTC
+	///
+	/// See [`Stamp::submitted_range()`] for how the day is delimited.
+	pub fn submitted_range_chrono(
+		&self,
+	) -> Result<Range<chrono::DateTime<chrono::Utc>>, JiffError> {
+		let Range { start, end } = self.submitted_timestamps()?;
+		let convert = |timestamp: Timestamp| {
+			chrono::DateTime::from_timestamp(timestamp.as_second(), 0)
+				.expect("every jiff timestamp is a valid chrono timestamp")
+		};
+		Ok(convert(start)..convert(end))
+	}
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl Stamp<'_> {
+	/// The submission date as a [`time::Date`]
+	///
+	/// ```
+	/// use arxiv::Stamp;
+	///
+	/// let stamp = Stamp::try_from("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// assert_eq!(stamp.submitted_time().to_string(), "2020-01-01");
+	/// ```
+	#[must_use]
+	pub fn submitted_time(&self) -> time::Date {
+		let date = self.submitted;
+		let month = time::Month::try_from(date.month().unsigned_abs())
+			.expect("every jiff month is a valid time month");
+		time::Date::from_calendar_date(i32::from(date.year()), month, date.day().unsigned_abs())
+			.expect("every jiff date is a valid time date")
+	}
+
+	/// The time span of the submission day as [`time::OffsetDateTime`]s in UTC
+	///
+	/// See [`Stamp::submitted_range()`] for how the day is delimited.
+	pub fn submitted_range_time(&self) -> Result<Range<time::OffsetDateTime>, JiffError> {
+		let Range { start, end } = self.submitted_timestamps()?;
+		let convert = |timestamp: Timestamp| {
+			time::OffsetDateTime::from_unix_timestamp(timestamp.as_second())
+				.expect("every jiff timestamp is a valid time timestamp")
+		};
+		Ok(convert(start)..convert(end))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Stamp, StampDateFormat};
+
+	#[test]
+	fn daylight_saving_time() {
+		let stamp = Stamp::try_from("arXiv:2403.00001 [cs.LG] 10 Mar 2024").unwrap();
+		let day = stamp.submitted_timestamps().unwrap();
+		assert_eq!(day.start.to_string(), "2024-03-10T05:00:00Z");
+		assert_eq!(day.end.to_string(), "2024-03-11T04:00:00Z");
+		assert_eq!(
+			stamp.format_date(StampDateFormat::Rfc2822).unwrap(),
+			"Sun, 10 Mar 2024 00:00:00 -0500"
+		);
+	}
+
+	#[test]
+	fn formats_keep_the_stamp() {
+		let stamp = Stamp::try_from("arXiv:hep-th/9901001v2 3 Feb 1999").unwrap();
+		assert_eq!(stamp.to_string_with(StampDateFormat::Arxiv).unwrap(), stamp.to_string());
+		assert_eq!(
+			stamp.to_string_with(StampDateFormat::Rfc2822).unwrap(),
+			"arXiv:hep-th/9901001v2 Wed, 3 Feb 1999 00:00:00 -0500"
+		);
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "chrono")]
+mod tests_chrono {
+	use crate::Stamp;
+
+	#[test]
+	fn range() {
+		let stamp = Stamp::try_from("arXiv:2007.00001 [cs.LG] 1 Jul 2020").unwrap();
+		let day = stamp.submitted_range_chrono().unwrap();
+		assert_eq!(day.start.timestamp(), 1_593_576_000);
+		assert_eq!((day.end - day.start).num_hours(), 24);
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "time")]
+mod tests_time {
+	use crate::Stamp;
+
+	#[test]
+	fn range() {
+		let stamp = Stamp::try_from("arXiv:2011.00001 [cs.LG] 1 Nov 2020").unwrap();
+		let day = stamp.submitted_range_time().unwrap();
+		assert_eq!(day.start.unix_timestamp(), 1_604_203_200);
+		assert_eq!((day.end - day.start).whole_hours(), 25);
+	}
+}
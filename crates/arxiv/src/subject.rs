@@ -0,0 +1,466 @@
+use crate::{Archive, CategoryId};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Generates a subject class enum for an archive from its list of subject codes and names.
+macro_rules! subject_enum {
+	(
+		$(#[$meta:meta])*
+		$name:ident => $archive:ident {
+			$($variant:ident => $code:literal, $label:literal;)+
+		}
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+		pub enum $name {
+			$(
+				#[doc = $label]
+				$variant,
+			)+
+		}
+
+		impl $name {
+			/// All subject classes of the archive, in canonical order
+			pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+			/// The archive that the subject class belongs to
+			pub const ARCHIVE: Archive = Archive::$archive;
+
+			pub(crate) const SUBJECTS: &'static [Subject] = &[$(Subject::$archive(Self::$variant)),+];
+
+			/// Parses a subject class from its code, as written after the archive in a category
+			/// identifier (e.g. `LG` in `cs.LG`).
+			#[must_use]
+			pub fn from_code(code: &str) -> Option<Self> {
+				match code {
+					$($code => Some(Self::$variant),)+
+					_ => None,
+				}
+			}
+
+			/// The code of the subject class, as written after the archive in a category identifier
+			#[must_use]
+			pub const fn as_str(&self) -> &'static str {
+				match self {
+					$(Self::$variant => $code,)+
+				}
+			}
+
+			/// The human-readable name of the subject class
+			#[must_use]
+			pub const fn name(&self) -> &'static str {
+				match self {
+					$(Self::$variant => $label,)+
+				}
+			}
+		}
+
+		impl Display for $name {
+			fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+				f.write_str(self.as_str())
+			}
+		}
+
+		impl From<$name> for Subject {
+			fn from(subject: $name) -> Self {
+				Self::$archive(subject)
+			}
+		}
+
+		impl From<$name> for CategoryId {
+			fn from(subject: $name) -> Self {
+				Self::from(Subject::from(subject))
+			}
+		}
+	};
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::AstroPh`] archive
+	AstroPhSubject => AstroPh {
+		Co => "CO", "Cosmology and Nongalactic Astrophysics";
+		Ep => "EP", "Earth and Planetary Astrophysics";
+		Ga => "GA", "Astrophysics of Galaxies";
+		He => "HE", "High Energy Astrophysical Phenomena";
+		Im => "IM", "Instrumentation and Methods for Astrophysics";
+		Sr => "SR", "Solar and Stellar Astrophysics";
+	}
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::CondMat`] archive
+	CondMatSubject => CondMat {
+		DisNn => "dis-nn", "Disordered Systems and Neural Networks";
+		MesHall => "mes-hall", "Mesoscale and Nanoscale Physics";
+		MtrlSci => "mtrl-sci", "Materials Science";
+		Other => "other", "Other Condensed Matter";
+		QuantGas => "quant-gas", "Quantum Gases";
+		Soft => "soft", "Soft Condensed Matter";
+		StatMech => "stat-mech", "Statistical Mechanics";
+		StrEl => "str-el", "Strongly Correlated Electrons";
+		SuprCon => "supr-con", "Superconductivity";
+	}
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::Cs`] archive
+	CsSubject => Cs {
+		Ai => "AI", "Artificial Intelligence";
+		Ar => "AR", "Hardware Architecture";
+		Cc => "CC", "Computational Complexity";
+		Ce => "CE", "Computational Engineering, Finance, and Science";
+		Cg => "CG", "Computational Geometry";
+		Cl => "CL", "Computation and Language";
+		Cr => "CR", "Cryptography and Security";
+		Cv => "CV", "Computer Vision and Pattern Recognition";
+		Cy => "CY", "Computers and Society";
+		Db => "DB", "Databases";
+		Dc => "DC", "Distributed, Parallel, and Cluster Computing";
+		Dl => "DL", "Digital Libraries";
+		Dm => "DM", "Discrete Mathematics";
+		Ds => "DS", "Data Structures and Algorithms";
+		Et => "ET", "Emerging Technologies";
+		Fl => "FL", "Formal Languages and Automata Theory";
+		Gl => "GL", "General Literature";
+		Gr => "GR", "Graphics";
+		Gt => "GT", "Computer Science and Game Theory";
+		Hc => "HC", "Human-Computer Interaction";
+		Ir => "IR", "Information Retrieval";
+		It => "IT", "Information Theory";
+		Lg => "LG", "Machine Learning";
+		Lo => "LO", "Logic in Computer Science";
+		Ma => "MA", "Multiagent Systems";
+		Mm => "MM", "Multimedia";
+		Ms => "MS", "Mathematical Software";
+		Na => "NA", "Numerical Analysis";
+		Ne => "NE", "Neural and Evolutionary Computing";
+		Ni => "NI", "Networking and Internet Architecture";
+		Oh => "OH", "Other Computer Science";
+		Os => "OS", "Operating Systems";
+		Pf => "PF", "Performance";
+		Pl => "PL", "Programming Languages";
+		Ro => "RO", "Robotics";
+		Sc => "SC", "Symbolic Computation";
+		Sd => "SD", "Sound";
+		Se => "SE", "Software Engineering";
+		Si => "SI", "Social and Information Networks";
+		Sy => "SY", "Systems and Control";
+	}
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::Econ`] archive
+	EconSubject => Econ {
+		Em => "EM", "Econometrics";
+		Gn => "GN", "General Economics";
+		Th => "TH", "Theoretical Economics";
+	}
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::Eess`] archive
+	EessSubject => Eess {
+		As => "AS", "Audio and Speech Processing";
+		Iv => "IV", "Image and Video Processing";
+		Sp => "SP", "Signal Processing";
+		Sy => "SY", "Systems and Control";
+	}
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::Math`] archive
+	MathSubject => Math {
+		Ac => "AC", "Commutative Algebra";
+		Ag => "AG", "Algebraic Geometry";
+		Ap => "AP", "Analysis of PDEs";
+		At => "AT", "Algebraic Topology";
+		Ca => "CA", "Classical Analysis and ODEs";
+		Co => "CO", "Combinatorics";
+		Ct => "CT", "Category Theory";
+		Cv => "CV", "Complex Variables";
+		Dg => "DG", "Differential Geometry";
+		Ds => "DS", "Dynamical Systems";
+		Fa => "FA", "Functional Analysis";
+		Gm => "GM", "General Mathematics";
+		Gn => "GN", "General Topology";
+		Gr => "GR", "Group Theory";
+		Gt => "GT", "Geometric Topology";
+		Ho => "HO", "History and Overview";
+		It => "IT", "Information Theory";
+		Kt => "KT", "K-Theory and Homology";
+		Lo => "LO", "Logic";
+		Mg => "MG", "Metric Geometry";
+		Mp => "MP", "Mathematical Physics";
+		Na => "NA", "Numerical Analysis";
+		Nt => "NT", "Number Theory";
+		Oa => "OA", "Operator Algebras";
+		Oc => "OC", "Optimization and Control";
+		Pr => "PR", "Probability";
+		Qa => "QA", "Quantum Algebra";
+		Ra => "RA", "Rings and Algebras";
+		Rt => "RT", "Representation Theory";
+		Sg => "SG", "Symplectic Geometry";
+		Sp => "SP", "Spectral Theory";
+		St => "ST", "Statistics Theory";
+	}
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::Nlin`] archive
+	NlinSubject => Nlin {
+		Ao => "AO", "Adaptation and Self-Organizing Systems";
+		Cd => "CD", "Chaotic Dynamics";
+		Cg => "CG", "Cellular Automata and Lattice Gases";
+		Ps => "PS", "Pattern Formation and Solitons";
+		Si => "SI", "Exactly Solvable and Integrable Systems";
+	}
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::Physics`] archive
+	PhysicsSubject => Physics {
+		AccPh => "acc-ph", "Accelerator Physics";
+		AoPh => "ao-ph", "Atmospheric and Oceanic Physics";
+		AppPh => "app-ph", "Applied Physics";
+		AtmClus => "atm-clus", "Atomic and Molecular Clusters";
+		AtomPh => "atom-ph", "Atomic Physics";
+		BioPh => "bio-ph", "Biological Physics";
+		ChemPh => "chem-ph", "Chemical Physics";
+		ClassPh => "class-ph", "Classical Physics";
+		CompPh => "comp-ph", "Computational Physics";
+		DataAn => "data-an", "Data Analysis, Statistics and Probability";
+		EdPh => "ed-ph", "Physics Education";
+		FluDyn => "flu-dyn", "Fluid Dynamics";
+		GenPh => "gen-ph", "General Physics";
+		GeoPh => "geo-ph", "Geophysics";
+		HistPh => "hist-ph", "History and Philosophy of Physics";
+		InsDet => "ins-det", "Instrumentation and Detectors";
+		MedPh => "med-ph", "Medical Physics";
+		Optics => "optics", "Optics";
+		PlasmPh => "plasm-ph", "Plasma Physics";
+		PopPh => "pop-ph", "Popular Physics";
+		SocPh => "soc-ph", "Physics and Society";
+		SpacePh => "space-ph", "Space Physics";
+	}
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::QBio`] archive
+	QBioSubject => QBio {
+		Bm => "BM", "Biomolecules";
+		Cb => "CB", "Cell Behavior";
+		Gn => "GN", "Genomics";
+		Mn => "MN", "Molecular Networks";
+		Nc => "NC", "Neurons and Cognition";
+		Ot => "OT", "Other Quantitative Biology";
+		Pe => "PE", "Populations and Evolution";
+		Qm => "QM", "Quantitative Methods";
+		Sc => "SC", "Subcellular Processes";
+		To => "TO", "Tissues and Organs";
+	}
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::QFin`] archive
+	QFinSubject => QFin {
+		Cp => "CP", "Computational Finance";
+		Ec => "EC", "Economics";
+		Gn => "GN", "General Finance";
+		Mf => "MF", "Mathematical Finance";
+		Pm => "PM", "Portfolio Management";
+		Pr => "PR", "Pricing of Securities";
+		Rm => "RM", "Risk Management";
+		St => "ST", "Statistical Finance";
+		Tr => "TR", "Trading and Market Microstructure";
+	}
+}
+
+subject_enum! {
+	/// Subject classes of the [`Archive::Stat`] archive
+	StatSubject => Stat {
+		Ap => "AP", "Applications";
+		Co => "CO", "Computation";
+		Me => "ME", "Methodology";
+		Ml => "ML", "Machine Learning";
+		Ot => "OT", "Other Statistics";
+		Th => "TH", "Statistics Theory";
+	}
+}
+
+/// A subject class nested under an archive, such as `LG` in `cs.LG`
+///
+/// Each variant wraps the subject classes of a single archive, so matching on a [`Subject`]
+/// can be done exhaustively. Archives without any subject classes (such as [`Archive::HepTh`])
+/// have no corresponding variant.
+///
+/// # Examples
+/// ```
+/// use arxiv::{Archive, CategoryId, CsSubject, Subject};
+///
+/// let category = CategoryId::try_from("cs.LG").unwrap();
+/// assert_eq!(category.subject(), Some(Subject::Cs(CsSubject::Lg)));
+/// assert_eq!(Subject::Cs(CsSubject::Lg).archive(), Archive::Cs);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Subject {
+	AstroPh(AstroPhSubject),
+	CondMat(CondMatSubject),
+	Cs(CsSubject),
+	Econ(EconSubject),
+	Eess(EessSubject),
+	Math(MathSubject),
+	Nlin(NlinSubject),
+	Physics(PhysicsSubject),
+	QBio(QBioSubject),
+	QFin(QFinSubject),
+	Stat(StatSubject),
+}
+
+impl Subject {
+	/// Parses the subject class code of the given archive.
+	///
+	/// Returns [`None`] if the archive has no subject class with that code, which includes
+	/// every code for archives without any subject classes.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::{Archive, AstroPhSubject, Subject};
+	///
+	/// let subject = Subject::from_code(Archive::AstroPh, "HE");
+	/// assert_eq!(subject, Some(Subject::AstroPh(AstroPhSubject::He)));
+	/// assert_eq!(Subject::from_code(Archive::HepTh, "HE"), None);
+	/// ```
+	#[must_use]
+	pub fn from_code(archive: Archive, code: &str) -> Option<Self> {
+		match archive {
+			Archive::AstroPh => AstroPhSubject::from_code(code).map(Self::from),
+			Archive::CondMat => CondMatSubject::from_code(code).map(Self::from),
+			Archive::Cs => CsSubject::from_code(code).map(Self::from),
+			Archive::Econ => EconSubject::from_code(code).map(Self::from),
+			Archive::Eess => EessSubject::from_code(code).map(Self::from),
+			Archive::Math => MathSubject::from_code(code).map(Self::from),
+			Archive::Nlin => NlinSubject::from_code(code).map(Self::from),
+			Archive::Physics => PhysicsSubject::from_code(code).map(Self::from),
+			Archive::QBio => QBioSubject::from_code(code).map(Self::from),
+			Archive::QFin => QFinSubject::from_code(code).map(Self::from),
+			Archive::Stat => StatSubject::from_code(code).map(Self::from),
+			_ => None,
+		}
+	}
+
+	/// The archive that the subject class belongs to
+	#[must_use]
+	pub const fn archive(&self) -> Archive {
+		match self {
+			Self::AstroPh(_) => AstroPhSubject::ARCHIVE,
+			Self::CondMat(_) => CondMatSubject::ARCHIVE,
+			Self::Cs(_) => CsSubject::ARCHIVE,
+			Self::Econ(_) => EconSubject::ARCHIVE,
+			Self::Eess(_) => EessSubject::ARCHIVE,
+			Self::Math(_) => MathSubject::ARCHIVE,
+			Self::Nlin(_) => NlinSubject::ARCHIVE,
+			Self::Physics(_) => PhysicsSubject::ARCHIVE,
+			Self::QBio(_) => QBioSubject::ARCHIVE,
+			Self::QFin(_) => QFinSubject::ARCHIVE,
+			Self::Stat(_) => StatSubject::ARCHIVE,
+		}
+	}
+
+	/// The code of the subject class, as written after the archive in a category identifier
+	#[must_use]
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::AstroPh(s) => s.as_str(),
+			Self::CondMat(s) => s.as_str(),
+			Self::Cs(s) => s.as_str(),
+			Self::Econ(s) => s.as_str(),
+			Self::Eess(s) => s.as_str(),
+			Self::Math(s) => s.as_str(),
+			Self::Nlin(s) => s.as_str(),
+			Self::Physics(s) => s.as_str(),
+			Self::QBio(s) => s.as_str(),
+			Self::QFin(s) => s.as_str(),
+			Self::Stat(s) => s.as_str(),
+		}
+	}
+
+	/// The human-readable name of the subject class
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		match self {
+			Self::AstroPh(s) => s.name(),
+			Self::CondMat(s) => s.name(),
+			Self::Cs(s) => s.name(),
+			Self::Econ(s) => s.name(),
+			Self::Eess(s) => s.name(),
+			Self::Math(s) => s.name(),
+			Self::Nlin(s) => s.name(),
+			Self::Physics(s) => s.name(),
+			Self::QBio(s) => s.name(),
+			Self::QFin(s) => s.name(),
+			Self::Stat(s) => s.name(),
+		}
+	}
+
+	/// The position of the subject class within [`Archive::subjects()`]
+	pub(crate) const fn position(&self) -> usize {
+		match self {
+			Self::AstroPh(s) => *s as usize,
+			Self::CondMat(s) => *s as usize,
+			Self::Cs(s) => *s as usize,
+			Self::Econ(s) => *s as usize,
+			Self::Eess(s) => *s as usize,
+			Self::Math(s) => *s as usize,
+			Self::Nlin(s) => *s as usize,
+			Self::Physics(s) => *s as usize,
+			Self::QBio(s) => *s as usize,
+			Self::QFin(s) => *s as usize,
+			Self::Stat(s) => *s as usize,
+		}
+	}
+}
+
+impl Display for Subject {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(self.as_str())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Archive, CondMatSubject, CsSubject, MathSubject, Subject};
+
+	#[test]
+	fn from_code() {
+		let subject = Subject::from_code(Archive::CondMat, "str-el");
+		assert_eq!(subject, Some(Subject::CondMat(CondMatSubject::StrEl)));
+	}
+
+	#[test]
+	fn from_code_wrong_archive() {
+		assert_eq!(Subject::from_code(Archive::Math, "LG"), None);
+		assert_eq!(Subject::from_code(Archive::QuantPh, ""), None);
+	}
+
+	#[test]
+	fn same_code_different_archives() {
+		let cs = Subject::from(CsSubject::It);
+		let math = Subject::from(MathSubject::It);
+		assert_ne!(cs, math);
+		assert_eq!(cs.as_str(), math.as_str());
+		assert_eq!(cs.archive(), Archive::Cs);
+		assert_eq!(math.archive(), Archive::Math);
+	}
+
+	#[test]
+	fn codes_roundtrip() {
+		for subject in CsSubject::ALL {
+			assert_eq!(CsSubject::from_code(subject.as_str()), Some(*subject));
+		}
+	}
+
+	#[test]
+	fn name() {
+		assert_eq!(CsSubject::Lg.name(), "Machine Learning");
+		assert_eq!(Subject::from(MathSubject::Ag).to_string(), "AG");
+	}
+}
@@ -0,0 +1,41 @@
+/// Computes the Levenshtein distance between two strings, ignoring ASCII case.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+	let b: Vec<u8> = b.bytes().map(|c| c.to_ascii_lowercase()).collect();
+	let mut row: Vec<usize> = (0..=b.len()).collect();
+
+	for (i, ca) in a.bytes().map(|c| c.to_ascii_lowercase()).enumerate() {
+		let mut diagonal = row[0];
+		row[0] = i + 1;
+		for (j, cb) in b.iter().enumerate() {
+			let substitution = diagonal + usize::from(ca != *cb);
+			diagonal = row[j + 1];
+			row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+		}
+	}
+
+	row[b.len()]
+}
+
+/// The maximum edit distance for a string to be considered a misspelling of another,
+/// which allows roughly one typo for every three characters.
+pub(crate) const fn max_distance(s: &str) -> usize {
+	s.len() / 3
+}
+
+#[cfg(test)]
+mod tests {
+	use super::edit_distance;
+
+	#[test]
+	fn distance() {
+		assert_eq!(edit_distance("", ""), 0);
+		assert_eq!(edit_distance("astroph", "astro-ph"), 1);
+		assert_eq!(edit_distance("kitten", "sitting"), 3);
+		assert_eq!(edit_distance("", "abc"), 3);
+	}
+
+	#[test]
+	fn distance_ignores_case() {
+		assert_eq!(edit_distance("CS", "cs"), 0);
+	}
+}
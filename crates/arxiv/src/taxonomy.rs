@@ -0,0 +1,542 @@
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+use crate::{Archive, CategoryId, Group};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An error that can occur when parsing a category against a [`Taxonomy`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxonomyError<'a> {
+	ExpectedSubject,
+	InvalidArchive(&'a str),
+	InvalidArchiveSubject(&'a str, &'a str),
+}
+
+impl Error for TaxonomyError<'_> {}
+
+impl Display for TaxonomyError<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::ExpectedSubject => f.write_str("Expected to find a subject identifier"),
+			Self::InvalidArchive(s) => write!(f, "Invalid arXiv archive identifier: {s}"),
+			Self::InvalidArchiveSubject(archive, subject) => write!(
+				f,
+				"The arXiv subject \"{subject}\" does not fall under the archive \"{archive}\""
+			),
+		}
+	}
+}
+
+/// A set of archives and their subject classes, which categories can be parsed against
+///
+/// The taxonomy compiled into the crate (which [`CategoryId`] is validated against) is available
+/// through [`Taxonomy::default()`]. Since arXiv introduces new categories more often than this
+/// crate is released, a newer taxonomy can also be loaded at runtime from JSON (with the `json`
+/// feature), TOML (with the `toml` feature) or YAML (with the `yaml` feature), and exported back
+/// to any of these formats or to a Graphviz graph.
+///
+/// # Limitations
+/// Categories that are only defined by a loaded taxonomy can be parsed, named and exported, but
+/// have no [`CategoryId`] ([`TaxonomyCategory::category_id()`] returns `None`). Since
+/// [`CategoryId`] and the types built on it ([`Stamp`](crate::Stamp),
+/// [`CategorySet`](crate::CategorySet) and [`ArticleCategories`](crate::ArticleCategories)) are
+/// limited to the taxonomy compiled into the crate, they cannot hold such categories.
+///
+/// An archive with subject classes is only a category of its own if the crate knows it to have
+/// been divided into subject classes later, like `astro-ph` (see [`CategoryId::from_archive()`]).
+///
+/// # Examples
+/// ```
+/// use arxiv::{Taxonomy, TaxonomySubject};
+///
+/// let mut taxonomy = Taxonomy::default();
+/// assert!(taxonomy.parse_category("cs.XX").is_err());
+///
+/// taxonomy.archive_mut("cs").unwrap().subjects.push(TaxonomySubject {
+///     id: "XX".to_owned(),
+///     name: "Experimental Computer Science".to_owned(),
+///     alias: None,
+/// });
+///
+/// let category = taxonomy.parse_category("cs.XX").unwrap();
+/// assert_eq!(category.to_string(), "cs.XX");
+/// assert_eq!(category.name(), "Experimental Computer Science");
+/// assert_eq!(category.category_id(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Taxonomy {
+	pub archives: Vec<TaxonomyArchive>,
+}
+
+/// An archive, as defined by a [`Taxonomy`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TaxonomyArchive {
+	/// The identifier of the archive (e.g. `astro-ph`)
+	pub id: String,
+	/// The human-readable name of the archive
+	pub name: String,
+	/// The identifier of the group the archive belongs to (e.g. `physics`)
+	pub group: String,
+	/// The category that a legacy archive was subsumed into, if any
+	#[cfg_attr(
+		feature = "serde",
+		serde(default, skip_serializing_if = "Option::is_none")
+	)]
+	pub subsumed_into: Option<String>,
+	/// The equivalent category of an archive without subject classes, if any (e.g. `math.MP` for
+	/// `math-ph`)
+	#[cfg_attr(
+		feature = "serde",
+		serde(default, skip_serializing_if = "Option::is_none")
+	)]
+	pub alias: Option<String>,
+	/// The subject classes nested under the archive, which is empty if it has none
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub subjects: Vec<TaxonomySubject>,
+}
+
+impl TaxonomyArchive {
+	/// Checks if the archive is a category of its own, and not only through its subject classes.
+	fn is_category(&self) -> bool {
+		self.subjects.is_empty()
+			|| Archive::from_code(&self.id)
+				.is_some_and(|archive| CategoryId::from_archive(archive).is_some())
+	}
+}
+
+/// A subject class nested under an archive, as defined by a [`Taxonomy`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TaxonomySubject {
+	/// The identifier of the subject class (e.g. `HE` in `astro-ph.HE`)
+	pub id: String,
+	/// The human-readable name of the subject class
+	pub name: String,
+	/// The equivalent category listed under another archive, if any (e.g. `eess.SY` for `cs.SY`)
+	#[cfg_attr(
+		feature = "serde",
+		serde(default, skip_serializing_if = "Option::is_none")
+	)]
+	pub alias: Option<String>,
+}
+
+/// A category that was parsed against a [`Taxonomy`], borrowing its definition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaxonomyCategory<'t> {
+	archive: &'t TaxonomyArchive,
+	subject: Option<&'t TaxonomySubject>,
+}
+
+impl Taxonomy {
+	/// Loads a taxonomy from JSON.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Taxonomy;
+	///
+	/// let taxonomy = Taxonomy::from_json(r#"{
+	///     "archives": [
+	///         { "id": "hep-th", "name": "High Energy Physics - Theory", "group": "physics" }
+	///     ]
+	/// }"#).unwrap();
+	/// assert!(taxonomy.parse_category("hep-th").is_ok());
+	/// ```
+	#[cfg(feature = "json")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+	pub fn from_json(s: &str) -> Result<Self, serde_json::Error> {
+		serde_json::from_str(s)
+	}
+
+	/// Loads a taxonomy from TOML.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Taxonomy;
+	///
+	/// let taxonomy = Taxonomy::from_toml(r#"
+	///     [[archives]]
+	///     id = "econ"
+	///     name = "Economics"
+	///     group = "econ"
+	///
+	///     [[archives.subjects]]
+	///     id = "EM"
+	///     name = "Econometrics"
+	/// "#).unwrap();
+	/// assert!(taxonomy.parse_category("econ.EM").is_ok());
+	/// assert!(taxonomy.parse_category("econ.GN").is_err());
+	/// ```
+	#[cfg(feature = "toml")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+	pub fn from_toml(s: &str) -> Result<Self, toml::de::Error> {
+		toml::from_str(s)
+	}
+
+	/// Loads a taxonomy from YAML.
+	#[cfg(feature = "yaml")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "yaml")))]
+	pub fn from_yaml(s: &str) -> Result<Self, serde_norway::Error> {
+		serde_norway::from_str(s)
+	}
+
+	/// Exports the taxonomy as pretty-printed JSON.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Taxonomy;
+	///
+	/// let json = Taxonomy::default().to_json().unwrap();
+	/// assert!(json.contains(r#""id": "astro-ph""#));
+	/// ```
+	#[cfg(feature = "json")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+	pub fn to_json(&self) -> Result<String, serde_json::Error> {
+		serde_json::to_string_pretty(self)
+	}
+
+	/// Exports the taxonomy as TOML.
+	#[cfg(feature = "toml")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+	pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+		toml::to_string(self)
+	}
+
+	/// Exports the taxonomy as YAML.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Taxonomy;
+	///
+	/// let yaml = Taxonomy::default().to_yaml().unwrap();
+	/// assert!(yaml.contains("subsumed_into: nlin.CD"));
+	/// ```
+	#[cfg(feature = "yaml")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "yaml")))]
+	pub fn to_yaml(&self) -> Result<String, serde_norway::Error> {
+		serde_norway::to_string(self)
+	}
+
+	/// Exports the group → archive → category hierarchy as a [Graphviz] DOT graph.
+	///
+	/// Groups are labelled by name when they are known to the crate. Legacy archives are drawn
+	/// with a dashed outline and an edge to the category they were subsumed into, while aliases are
+	/// connected by dotted edges.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Taxonomy;
+	///
+	/// let dot = Taxonomy::default().to_dot();
+	/// assert!(dot.starts_with("digraph taxonomy {"));
+	/// assert!(dot.contains(r#""group/cs" -> "cs";"#));
+	/// assert!(dot.contains(r#""cs" -> "cs.LG";"#));
+	/// ```
+	///
+	/// [Graphviz]: <https://graphviz.org/doc/info/lang.html>
+	#[must_use]
+	pub fn to_dot(&self) -> String {
+		let mut dot = String::from("digraph taxonomy {\n\trankdir=LR;\n");
+		let mut groups: Vec<&str> = Vec::new();
+		let mut aliases: Vec<(String, &str)> = Vec::new();
+
+		// writing into a String never fails
+		for archive in &self.archives {
+			if !groups.contains(&archive.group.as_str()) {
+				groups.push(&archive.group);
+				let label =
+					Group::from_str(&archive.group).map_or(archive.group.as_str(), |g| g.name());
+				let _ = writeln!(
+					dot,
+					"\t\"group/{}\" [label={}, shape=box];",
+					archive.group,
+					quote(label)
+				);
+			}
+
+			let style = match archive.subsumed_into {
+				Some(_) => ", style=dashed",
+				None => "",
+			};
+			let _ =
+				writeln!(dot, "\t{} [label={}{style}];", quote(&archive.id), quote(&archive.name));
+			let _ = writeln!(dot, "\t\"group/{}\" -> {};", archive.group, quote(&archive.id));
+			if let Some(category) = &archive.subsumed_into {
+				let _ = writeln!(
+					dot,
+					"\t{} -> {} [style=dashed];",
+					quote(&archive.id),
+					quote(category)
+				);
+			}
+			if let Some(alias) = &archive.alias {
+				aliases.push((archive.id.clone(), alias));
+			}
+
+			for subject in &archive.subjects {
+				let id = format!("{}.{}", archive.id, subject.id);
+				let _ = writeln!(dot, "\t{} [label={}];", quote(&id), quote(&subject.name));
+				let _ = writeln!(dot, "\t{} -> {};", quote(&archive.id), quote(&id));
+				if let Some(alias) = &subject.alias {
+					aliases.push((id, alias));
+				}
+			}
+		}
+
+		// draw each pair of aliases once
+		for (id, alias) in &aliases {
+			if id.as_str() < *alias || !aliases.iter().any(|(a, b)| a == alias && b == id) {
+				let _ =
+					writeln!(dot, "\t{} -> {} [style=dotted, dir=none];", quote(id), quote(alias));
+			}
+		}
+
+		dot.push_str("}\n");
+		dot
+	}
+
+	/// Finds an archive by its identifier.
+	#[must_use]
+	pub fn archive(&self, id: &str) -> Option<&TaxonomyArchive> {
+		self.archives.iter().find(|archive| archive.id == id)
+	}
+
+	/// Finds an archive by its identifier, allowing it to be modified.
+	#[must_use]
+	pub fn archive_mut(&mut self, id: &str) -> Option<&mut TaxonomyArchive> {
+		self.archives.iter_mut().find(|archive| archive.id == id)
+	}
+
+	/// Iterates over every category of the taxonomy, where an archive that is a category of its own
+	/// comes before its subject classes.
+	pub fn categories(&self) -> impl Iterator<Item = TaxonomyCategory<'_>> {
+		self.archives.iter().flat_map(|archive| {
+			let subjects = archive.subjects.iter().map(Some);
+			archive
+				.is_category()
+				.then_some(None)
+				.into_iter()
+				.chain(subjects)
+				.map(move |subject| TaxonomyCategory { archive, subject })
+		})
+	}
+
+	/// Parses a category like `astro-ph.HE` against the taxonomy.
+	///
+	/// This follows the same grammar as [`CategoryId::try_from()`], but accepts any category
+	/// defined by the taxonomy.
+	pub fn parse_category<'t, 's>(
+		&'t self,
+		s: &'s str,
+	) -> Result<TaxonomyCategory<'t>, TaxonomyError<'s>> {
+		use TaxonomyError::*;
+
+		let (archive_str, subject_str) = match s.split_once(CategoryId::TOKEN_DELIM) {
+			Some((_, "")) => return Err(ExpectedSubject),
+			Some((archive_str, subject_str)) => (archive_str, Some(subject_str)),
+			None => (s, None),
+		};
+
+		let archive = self
+			.archive(archive_str)
+			.ok_or(InvalidArchive(archive_str))?;
+		let subject = match subject_str {
+			None if archive.is_category() => None,
+			None => return Err(ExpectedSubject),
+			Some(subject_str) => Some(
+				archive
+					.subjects
+					.iter()
+					.find(|subject| subject.id == subject_str)
+					.ok_or(InvalidArchiveSubject(archive_str, subject_str))?,
+			),
+		};
+
+		Ok(TaxonomyCategory { archive, subject })
+	}
+}
+
+impl Default for Taxonomy {
+	/// The taxonomy compiled into the crate
+	fn default() -> Self {
+		let archives = Archive::ALL
+			.iter()
+			.map(|archive| TaxonomyArchive {
+				id: archive.as_str().to_owned(),
+				name: archive.name().to_owned(),
+				group: Group::from(*archive).as_str().to_owned(),
+				subsumed_into: archive.subsumed_into().map(|c| c.to_string()),
+				alias: CategoryId::from_archive(*archive)
+					.and_then(|category| category.alias())
+					.map(|alias| alias.to_string()),
+				subjects: archive
+					.subjects()
+					.iter()
+					.map(|subject| TaxonomySubject {
+						id: subject.as_str().to_owned(),
+						name: subject.name().to_owned(),
+						alias: CategoryId::from(*subject).alias().map(|a| a.to_string()),
+					})
+					.collect(),
+			})
+			.collect();
+
+		Self { archives }
+	}
+}
+
+/// Quotes a string as a DOT identifier.
+fn quote(s: &str) -> String {
+	format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl<'t> TaxonomyCategory<'t> {
+	/// The archive of the category
+	#[must_use]
+	#[inline]
+	pub fn archive(&self) -> &'t TaxonomyArchive {
+		self.archive
+	}
+
+	/// The subject class of the category, if the archive is divided into subject classes
+	#[must_use]
+	#[inline]
+	pub fn subject(&self) -> Option<&'t TaxonomySubject> {
+		self.subject
+	}
+
+	/// The human-readable name of the category, which is the name of the subject class if there
+	/// is one, or otherwise the name of the archive
+	#[must_use]
+	pub fn name(&self) -> &'t str {
+		match self.subject {
+			Some(subject) => &subject.name,
+			None => &self.archive.name,
+		}
+	}
+
+	/// Converts the category into a [`CategoryId`], if it is part of the taxonomy compiled into
+	/// the crate.
+	///
+	/// Returns `None` for categories that were added to the taxonomy at runtime, which therefore
+	/// cannot be used with [`Stamp`](crate::Stamp), [`CategorySet`](crate::CategorySet) or
+	/// [`ArticleCategories`](crate::ArticleCategories).
+	#[must_use]
+	pub fn category_id(&self) -> Option<CategoryId> {
+		let archive = Archive::from_code(&self.archive.id)?;
+		let subject = self.subject.map_or("", |subject| subject.id.as_str());
+		CategoryId::try_new(archive, subject)
+	}
+}
+
+impl Display for TaxonomyCategory<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self.subject {
+			Some(subject) => write!(f, "{}.{}", self.archive.id, subject.id),
+			None => f.write_str(&self.archive.id),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{CategoryId, CategorySet, Taxonomy, TaxonomyError};
+	use TaxonomyError::*;
+
+	#[test]
+	fn default_matches_compiled() {
+		let taxonomy = Taxonomy::default();
+		let categories: Vec<_> = taxonomy.categories().collect();
+		assert_eq!(categories.len(), CategorySet::CAPACITY);
+
+		for category in categories {
+			let id = category.category_id().unwrap();
+			assert_eq!(id.to_string(), category.to_string());
+			assert_eq!(taxonomy.parse_category(&id.to_string()), Ok(category));
+		}
+	}
+
+	#[test]
+	fn parse_err() {
+		let taxonomy = Taxonomy::default();
+		assert_eq!(taxonomy.parse_category("cs"), Err(ExpectedSubject));
+		assert_eq!(taxonomy.parse_category("cs."), Err(ExpectedSubject));
+		assert_eq!(taxonomy.parse_category("ecot.LG"), Err(InvalidArchive("ecot")));
+		assert_eq!(taxonomy.parse_category("econ.foo"), Err(InvalidArchiveSubject("econ", "foo")));
+	}
+
+	#[test]
+	fn dot_graph() {
+		let dot = Taxonomy::default().to_dot();
+		assert!(dot.contains("\t\"group/physics\" [label=\"Physics\", shape=box];\n"));
+		assert!(dot.contains("\t\"chao-dyn\" [label=\"Chaotic Dynamics\", style=dashed];\n"));
+		assert!(dot.contains("\t\"chao-dyn\" -> \"nlin.CD\" [style=dashed];\n"));
+		assert!(dot.contains("\t\"cs.SY\" -> \"eess.SY\" [style=dotted, dir=none];\n"));
+		assert!(!dot.contains("\t\"eess.SY\" -> \"cs.SY\""));
+		assert!(dot.contains("\t\"math-ph\" -> \"math.MP\" [style=dotted, dir=none];\n"));
+		assert!(dot.ends_with("}\n"));
+	}
+
+	#[test]
+	fn dot_quotes_labels() {
+		let mut taxonomy = Taxonomy::default();
+		taxonomy.archive_mut("cs").unwrap().name = "Say \"hi\"".to_owned();
+		assert!(
+			taxonomy
+				.to_dot()
+				.contains("\t\"cs\" [label=\"Say \\\"hi\\\"\"];\n")
+		);
+	}
+
+	#[test]
+	fn parse_matches_category_id() {
+		let taxonomy = Taxonomy::default();
+		let category = taxonomy.parse_category("astro-ph.HE").unwrap();
+		assert_eq!(category.category_id(), CategoryId::try_from("astro-ph.HE").ok());
+		assert_eq!(category.name(), "High Energy Astrophysical Phenomena");
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "json")]
+mod tests_json {
+	use crate::Taxonomy;
+
+	#[test]
+	fn default_roundtrip() {
+		let taxonomy = Taxonomy::default();
+		let json = taxonomy.to_json().unwrap();
+		assert_eq!(Taxonomy::from_json(&json).unwrap(), taxonomy);
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "yaml")]
+mod tests_yaml {
+	use crate::Taxonomy;
+
+	#[test]
+	fn default_roundtrip() {
+		let taxonomy = Taxonomy::default();
+		let yaml = taxonomy.to_yaml().unwrap();
+		assert_eq!(Taxonomy::from_yaml(&yaml).unwrap(), taxonomy);
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "toml")]
+mod tests_toml {
+	use crate::Taxonomy;
+
+	#[test]
+	fn default_roundtrip() {
+		let taxonomy = Taxonomy::default();
+		let toml = taxonomy.to_toml().unwrap();
+		assert_eq!(Taxonomy::from_toml(&toml).unwrap(), taxonomy);
+	}
+}
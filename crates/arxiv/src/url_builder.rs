@@ -0,0 +1,256 @@
+use crate::ArticleId;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use url::Url;
+
+/// An error that can occur when creating a [`UrlBuilder`]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlBuilderError {
+	/// The base URL has no path to resolve against, like `mailto:` or `data:` URLs
+	CannotBeABase,
+}
+
+impl Error for UrlBuilderError {}
+
+impl Display for UrlBuilderError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::CannotBeABase => f.write_str("The base URL cannot be a base for other URLs"),
+		}
+	}
+}
+
+/// Builds the URLs of an article's resources against a configurable base URL
+///
+/// The builder defaults to `https://arxiv.org/`, but can also point to a mirror (such as
+/// [`UrlBuilder::export`]) or a local server. Paths are version-aware: an identifier with a
+/// version links to that version, while one without links to the latest version.
+///
+/// # Examples
+/// ```
+/// use arxiv::{ArticleId, UrlBuilder};
+///
+/// let id = ArticleId::try_from("arXiv:2010.14462v2").unwrap();
+/// let urls = UrlBuilder::default();
+/// assert_eq!(urls.pdf(id).as_str(), "https://arxiv.org/pdf/2010.14462v2");
+///
+/// let urls = UrlBuilder::new("http://localhost:8080/arxiv".parse().unwrap()).unwrap();
+/// assert_eq!(urls.abs(id).as_str(), "http://localhost:8080/arxiv/abs/2010.14462v2");
+///
+/// let id = ArticleId::try_from("arXiv:hep-th/9901001").unwrap();
+/// assert_eq!(UrlBuilder::export().pdf(id).as_str(), "https://export.arxiv.org/pdf/hep-th/9901001");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UrlBuilder {
+	base: Url,
+}
+
+impl Default for UrlBuilder {
+	fn default() -> Self {
+		Self::new(Url::parse("https://arxiv.org/").unwrap()).unwrap()
+	}
+}
+
+impl UrlBuilder {
+	/// Creates a builder for the given base URL, under which every path is resolved.
+	///
+	/// Fails if the URL cannot be a base, since no path could be resolved against it.
+	///
+	/// ```
+	/// use arxiv::{UrlBuilder, UrlBuilderError};
+	///
+	/// let base = "mailto:arxiv@example.org".parse().unwrap();
+	/// assert_eq!(UrlBuilder::new(base), Err(UrlBuilderError::CannotBeABase));
+	/// ```
+	pub fn new(mut base: Url) -> Result<Self, UrlBuilderError> {
+		if base.cannot_be_a_base() {
+			return Err(UrlBuilderError::CannotBeABase);
+		}
+		if !base.path().ends_with('/') {
+			let path = format!("{}/", base.path());
+			base.set_path(&path);
+		}
+		Ok(Self { base })
+	}
+
+	/// Creates a builder for `export.arxiv.org`, the mirror that arXiv provides for programmatic
+	/// access.
+	#[must_use]
+	pub fn export() -> Self {
+		Self::new(Url::parse("https://export.arxiv.org/").unwrap()).unwrap()
+	}
+
+	/// The base URL under which every path is resolved
+	#[must_use]
+	#[inline]
+	pub fn base(&self) -> &Url {
+		&self.base
+	}
+
+	// the base can be a base, so joining a path and pushing path segments cannot fail
+	fn resource(&self, kind: &str, id: ArticleId<'_>) -> Url {
+		let path = format!("{kind}/{}{}", id.as_unique_ident(), id.version());
+		self.base.join(&path).unwrap()
+	}
+
+	/// The URL of the abstract page
+	#[must_use]
+	pub fn abs(&self, id: ArticleId<'_>) -> Url {
+		self.resource("abs", id)
+	}
+
+	/// The URL of the PDF
+	#[must_use]
+	pub fn pdf(&self, id: ArticleId<'_>) -> Url {
+		self.resource("pdf", id)
+	}
+
+	/// The URL of the HTML rendering, which is only available for some articles
+	#[must_use]
+	pub fn html(&self, id: ArticleId<'_>) -> Url {
+		self.resource("html", id)
+	}
+
+	/// The URL of the page listing the formats that the article can be downloaded in
+	#[must_use]
+	pub fn format(&self, id: ArticleId<'_>) -> Url {
+		self.resource("format", id)
+	}
+
+	/// The URL of the source files, as submitted by the authors
+	#[must_use]
+	pub fn eprint(&self, id: ArticleId<'_>) -> Url {
+		self.resource("e-print", id)
+	}
+
+	/// The URL of the source listing, under which ancillary files are found
+	#[must_use]
+	pub fn src(&self, id: ArticleId<'_>) -> Url {
+		self.resource("src", id)
+	}
+
+	/// The URL of an ancillary file
+	///
+	/// ```
+	/// use arxiv::{ArticleId, UrlBuilder};
+	///
+	/// let id = ArticleId::try_from("arXiv:2010.14462v2").unwrap();
+	/// let url = UrlBuilder::default().ancillary(id, "data/table 1.csv");
+	/// assert_eq!(url.as_str(), "https://arxiv.org/src/2010.14462v2/anc/data/table%201.csv");
+	/// ```
+	#[must_use]
+	pub fn ancillary(&self, id: ArticleId<'_>, file: &str) -> Url {
+		let mut url = self.src(id);
+		url.path_segments_mut()
+			.unwrap()
+			.push("anc")
+			.extend(file.split('/'));
+		url
+	}
+
+	/// The URLs of the abstract pages of every version up to the given one, in order
+	///
+	/// ```
+	/// use arxiv::{ArticleId, UrlBuilder};
+	///
+	/// let id = ArticleId::try_from("arXiv:2010.14462").unwrap();
+	/// let urls: Vec<_> = UrlBuilder::default().versions(id, 2).collect();
+	/// assert_eq!(urls[0].as_str(), "https://arxiv.org/abs/2010.14462v1");
+	/// assert_eq!(urls[1].as_str(), "https://arxiv.org/abs/2010.14462v2");
+	/// ```
+	pub fn versions<'b>(&'b self, id: ArticleId<'b>, latest: u8) -> impl Iterator<Item = Url> + 'b {
+		(1..=latest).map(move |version| {
+			let mut id = id;
+			id.set_version(version);
+			self.abs(id)
+		})
+	}
+
+	/// The URL of the BibTeX entry, which always describes the latest version
+	///
+	/// ```
+	/// use arxiv::{ArticleId, UrlBuilder};
+	///
+	/// let id = ArticleId::try_from("arXiv:2010.14462v2").unwrap();
+	/// let url = UrlBuilder::default().bibtex(id);
+	/// assert_eq!(url.as_str(), "https://arxiv.org/bibtex/2010.14462");
+	/// ```
+	#[must_use]
+	pub fn bibtex(&self, mut id: ArticleId<'_>) -> Url {
+		id.set_latest();
+		self.resource("bibtex", id)
+	}
+
+	/// The URL of the export API query for the article's metadata
+	#[must_use]
+	pub fn api_query(&self, id: ArticleId<'_>) -> Url {
+		let mut url = self.base.join("api/query").unwrap();
+		let ident = format!("{}{}", id.as_unique_ident(), id.version());
+		url.query_pairs_mut().append_pair("id_list", &ident);
+		url
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{ArticleId, UrlBuilder, UrlBuilderError};
+	use url::Url;
+
+	#[test]
+	fn resources() {
+		let id = ArticleId::try_from("arXiv:2010.14462v2").unwrap();
+		let urls = UrlBuilder::default();
+		assert_eq!(urls.html(id).as_str(), "https://arxiv.org/html/2010.14462v2");
+		assert_eq!(urls.format(id).as_str(), "https://arxiv.org/format/2010.14462v2");
+		assert_eq!(urls.eprint(id).as_str(), "https://arxiv.org/e-print/2010.14462v2");
+		assert_eq!(urls.src(id).as_str(), "https://arxiv.org/src/2010.14462v2");
+	}
+
+	#[test]
+	fn old_scheme() {
+		let id = ArticleId::try_from("arXiv:math.GT/0309136v1").unwrap();
+		let urls = UrlBuilder::default();
+		assert_eq!(urls.abs(id).as_str(), "https://arxiv.org/abs/math.GT/0309136v1");
+		assert_eq!(urls.bibtex(id).as_str(), "https://arxiv.org/bibtex/math.GT/0309136");
+		assert_eq!(
+			urls.ancillary(id, "a.txt").as_str(),
+			"https://arxiv.org/src/math.GT/0309136v1/anc/a.txt"
+		);
+	}
+
+	#[test]
+	fn base_path_is_kept() {
+		let id = ArticleId::try_from("arXiv:2010.14462").unwrap();
+		for base in [
+			"https://mirror.example/arxiv",
+			"https://mirror.example/arxiv/",
+		] {
+			let urls = UrlBuilder::new(Url::parse(base).unwrap()).unwrap();
+			assert_eq!(urls.base().as_str(), "https://mirror.example/arxiv/");
+			assert_eq!(urls.pdf(id).as_str(), "https://mirror.example/arxiv/pdf/2010.14462");
+		}
+	}
+
+	#[test]
+	fn base_must_be_a_base() {
+		for base in ["mailto:arxiv@example.org", "data:text/plain,arxiv"] {
+			let urls = UrlBuilder::new(Url::parse(base).unwrap());
+			assert_eq!(urls, Err(UrlBuilderError::CannotBeABase), "{base}");
+		}
+	}
+
+	#[test]
+	fn api_query() {
+		let id = ArticleId::try_from("arXiv:hep-th/9901001v2").unwrap();
+		let url = UrlBuilder::export().api_query(id);
+		assert_eq!(url.as_str(), "https://export.arxiv.org/api/query?id_list=hep-th%2F9901001v2");
+	}
+
+	#[test]
+	fn matches_article_url() {
+		let id = ArticleId::try_from("arXiv:2010.14462v2").unwrap();
+		assert_eq!(UrlBuilder::default().abs(id), Url::from(id));
+	}
+}
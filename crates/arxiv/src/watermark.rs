@@ -0,0 +1,143 @@
+use crate::Stamp;
+
+/// The grey level of the stamp, from black (0) to white (1)
+const GREY: &str = "0.5";
+
+/// The grey of the stamp as an RGB color
+const GREY_RGB: &str = "#808080";
+
+/// The font size of the stamp, in points
+const FONT_SIZE: u8 = 20;
+
+/// The distance between baselines of the stamp, in points
+const LEADING: u8 = 24;
+
+impl Stamp<'_> {
+	/// Renders the stamp as an SVG `<text>` element, rotated to read from bottom to top in the
+	/// grey that arXiv uses for its sidebar.
+	///
+	/// The text is centered on the origin, so that it can be placed by wrapping it in a group,
+	/// such as `<g transform="translate(32 396)">` for the left margin of a US Letter page.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Stamp;
+	///
+	/// let stamp = Stamp::try_from("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// assert_eq!(
+	///     stamp.to_svg(),
+	///     r##"<text transform="rotate(-90)" text-anchor="middle" font-family="Times, 'Times New Roman', serif" font-size="20" fill="#808080">arXiv:2001.00001 [cs.LG] 1 Jan 2020</text>"##
+	/// );
+	/// ```
+	#[must_use]
+	pub fn to_svg(&self) -> String {
+		format!(
+			"<text transform=\"rotate(-90)\" text-anchor=\"middle\" font-family=\"Times, 'Times New \
+			 Roman', serif\" font-size=\"{FONT_SIZE}\" fill=\"{GREY_RGB}\">{}</text>",
+			escape_xml(&self.to_string())
+		)
+	}
+
+	/// Renders the stamp as a LaTeX snippet that places it in the left margin of the first page,
+	/// rotated to read from bottom to top in the grey that arXiv uses for its sidebar.
+	///
+	/// The snippet belongs in the preamble or at the start of the document, and requires the
+	/// `eso-pic`, `graphicx` and `xcolor` packages.
+	///
+	/// # Examples
+	/// ```
+	/// use arxiv::Stamp;
+	///
+	/// let stamp = Stamp::try_from("arXiv:2001.00001 [cs.LG] 1 Jan 2020").unwrap();
+	/// let latex = stamp.to_latex();
+	/// assert!(latex.starts_with("\\AddToShipoutPictureBG*{"));
+	/// assert!(latex.contains("{arXiv:2001.00001 [cs.LG] 1 Jan 2020}"));
+	/// ```
+	#[must_use]
+	pub fn to_latex(&self) -> String {
+		format!(
+			"\\AddToShipoutPictureBG*{{%\n\
+			 \t\\put(\\LenToUnit{{0.45in}},\\LenToUnit{{0.5\\paperheight}}){{%\n\
+			 \t\t\\rotatebox{{90}}{{\\makebox[0pt][c]{{%\n\
+			 \t\t\t\\fontfamily{{ptm}}\\fontsize{{{FONT_SIZE}}}{{{LEADING}}}\\selectfont\\color[gray]{{{GREY}}}%\n\
+			 \t\t\t{{{}}}%\n\
+			 \t\t}}}}%\n\
+			 \t}}%\n\
+			 }}\n",
+			escape_latex(&self.to_string())
+		)
+	}
+}
+
+/// Escapes the characters that have a special meaning in XML text and attributes.
+fn escape_xml(s: &str) -> String {
+	let mut escaped = String::with_capacity(s.len());
+	for c in s.chars() {
+		match c {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&apos;"),
+			_ => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+/// Escapes the characters that have a special meaning in LaTeX text.
+fn escape_latex(s: &str) -> String {
+	let mut escaped = String::with_capacity(s.len());
+	for c in s.chars() {
+		match c {
+			'\\' => escaped.push_str("\\textbackslash{}"),
+			'~' => escaped.push_str("\\textasciitilde{}"),
+			'^' => escaped.push_str("\\textasciicircum{}"),
+			'&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+				escaped.push('\\');
+				escaped.push(c);
+			}
+			_ => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{escape_latex, escape_xml};
+	use crate::Stamp;
+
+	#[test]
+	fn svg() {
+		let stamp = Stamp::try_from("arXiv:hep-th/9901001v2 3 Feb 1999").unwrap();
+		let svg = stamp.to_svg();
+		assert!(svg.starts_with("<text transform=\"rotate(-90)\""));
+		assert!(svg.contains("fill=\"#808080\""));
+		assert!(svg.ends_with(">arXiv:hep-th/9901001v2 3 Feb 1999</text>"));
+	}
+
+	#[test]
+	fn latex() {
+		let stamp = Stamp::try_from("arXiv:0706.0001v1 [q-bio.CB] 1 Jun 2007").unwrap();
+		let expected = "\\AddToShipoutPictureBG*{%
+	\\put(\\LenToUnit{0.45in},\\LenToUnit{0.5\\paperheight}){%
+		\\rotatebox{90}{\\makebox[0pt][c]{%
+			\\fontfamily{ptm}\\fontsize{20}{24}\\selectfont\\color[gray]{0.5}%
+			{arXiv:0706.0001v1 [q-bio.CB] 1 Jun 2007}%
+		}}%
+	}%
+}
+";
+		assert_eq!(stamp.to_latex(), expected);
+	}
+
+	#[test]
+	fn escaping() {
+		assert_eq!(escape_xml("a<b & \"c\""), "a&lt;b &amp; &quot;c&quot;");
+		assert_eq!(
+			escape_latex("50% of $x_1$ {~}"),
+			"50\\% of \\$x\\_1\\$ \\{\\textasciitilde{}\\}"
+		);
+	}
+}